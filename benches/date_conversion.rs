@@ -1,4 +1,6 @@
 use criterion::{Criterion, black_box, criterion_group, criterion_main};
+use npdatetime::core::convert;
+use npdatetime::lookup::NepaliCalendar;
 use npdatetime::{NepaliDate, lookup};
 
 fn bench_days_in_month(c: &mut Criterion) {
@@ -35,6 +37,12 @@ fn bench_bs_to_ad_conversion(c: &mut Criterion) {
         b.iter(|| black_box(date.to_gregorian()));
     });
 
+    // Far from the epoch, where the distance to cover is largest
+    let late = NepaliDate::new(2099, 11, 20).unwrap();
+    group.bench_function("to_gregorian_late", |b| {
+        b.iter(|| black_box(late.to_gregorian()));
+    });
+
     group.finish();
 }
 
@@ -71,6 +79,58 @@ fn bench_date_arithmetic(c: &mut Criterion) {
     });
 }
 
+fn bench_bulk_conversion(c: &mut Criterion) {
+    let mut group = c.benchmark_group("bulk_conversion");
+    let dates: Vec<NepaliDate> = (1990..2090)
+        .flat_map(|y| (1..=12).map(move |m| NepaliDate::new(y, m, 15).unwrap()))
+        .collect();
+    let cal = NepaliCalendar::new();
+
+    group.bench_function("uncached_to_gregorian", |b| {
+        b.iter(|| {
+            for date in &dates {
+                black_box(date.to_gregorian().unwrap());
+            }
+        });
+    });
+
+    group.bench_function("cached_to_gregorian", |b| {
+        b.iter(|| {
+            for date in &dates {
+                black_box(cal.to_gregorian(date).unwrap());
+            }
+        });
+    });
+
+    group.bench_function("free_bs_to_ad", |b| {
+        b.iter(|| {
+            for date in &dates {
+                black_box(convert::bs_to_ad(date.year, date.month, date.day).unwrap());
+            }
+        });
+    });
+
+    let ad_dates: Vec<(i32, u8, u8)> = dates.iter().map(|d| d.to_gregorian().unwrap()).collect();
+
+    group.bench_function("method_from_gregorian", |b| {
+        b.iter(|| {
+            for &(y, m, d) in &ad_dates {
+                black_box(NepaliDate::from_gregorian(y, m, d).unwrap());
+            }
+        });
+    });
+
+    group.bench_function("free_ad_to_bs", |b| {
+        b.iter(|| {
+            for &(y, m, d) in &ad_dates {
+                black_box(convert::ad_to_bs(y, m, d).unwrap());
+            }
+        });
+    });
+
+    group.finish();
+}
+
 criterion_group!(
     benches,
     bench_days_in_month,
@@ -78,6 +138,7 @@ criterion_group!(
     bench_bs_to_ad_conversion,
     bench_ad_to_bs_conversion,
     bench_formatting,
-    bench_date_arithmetic
+    bench_date_arithmetic,
+    bench_bulk_conversion
 );
 criterion_main!(benches);
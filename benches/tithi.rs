@@ -0,0 +1,23 @@
+use criterion::{Criterion, black_box, criterion_group, criterion_main};
+use npdatetime::astronomical::TithiCalculator;
+use npdatetime::astronomical::core::JulianDay;
+
+fn bench_tithis_in_year(c: &mut Criterion) {
+    let start = JulianDay::from_gregorian(2024, 1, 1, 0.0);
+    let end = start.add_days(365.0);
+
+    c.bench_function("tithis_in_year", |b| {
+        b.iter(|| black_box(TithiCalculator::tithis_between(start, end).unwrap()));
+    });
+}
+
+fn bench_find_tithi_end(c: &mut Criterion) {
+    let approx = JulianDay::from_gregorian(2024, 1, 11, 0.0);
+
+    c.bench_function("find_tithi_end", |b| {
+        b.iter(|| black_box(TithiCalculator::find_tithi_end(0, approx).unwrap()));
+    });
+}
+
+criterion_group!(benches, bench_tithis_in_year, bench_find_tithi_end);
+criterion_main!(benches);
@@ -96,6 +96,21 @@ impl NepaliDate {
             .map_err(|e| JsValue::from_str(&e.to_string()))
     }
 
+    /// Parse a date string using a format string
+    /// 
+    /// @param {string} input - Date string (e.g. "2077-05-19")
+    /// @param {string} format - Format string (strftime-style)
+    /// @returns {NepaliDate} Parsed date
+    /// 
+    /// @example
+    /// const date = NepaliDate.parse("19 Bhadra 2077", "%d %B %Y");
+    /// console.log(date.toString()); // "2077-05-19"
+    pub fn parse(input: &str, format: &str) -> Result<NepaliDate, JsValue> {
+        npdatetime::NepaliDate::parse(input, format)
+            .map(|inner| NepaliDate { inner })
+            .map_err(|e| JsValue::from_str(&e.to_string()))
+    }
+
     /// Format the date as a string
     /// 
     /// @param {string} format - Format string (strftime-style)
@@ -119,6 +134,27 @@ impl NepaliDate {
             .map_err(|e| JsValue::from_str(&e.to_string()))
     }
 
+    /// Get the day of the week
+    /// 
+    /// @returns {string} English weekday name (e.g. "Friday")
+    pub fn weekday(&self) -> Result<String, JsValue> {
+        self.inner.weekday()
+            .map(|w| w.name().to_string())
+            .map_err(|e| JsValue::from_str(&e.to_string()))
+    }
+
+    /// Number of days from this date to another (negative if `other` is earlier)
+    /// 
+    /// @param {NepaliDate} other - Date to count to
+    /// @returns {number} Day difference
+    #[wasm_bindgen(js_name = daysBetween)]
+    pub fn days_between(&self, other: &NepaliDate) -> Result<i32, JsValue> {
+        // i64 would cross into JS as a BigInt; ordinals already fit in i32
+        self.inner.days_between(&other.inner)
+            .map(|days| days as i32)
+            .map_err(|e| JsValue::from_str(&e.to_string()))
+    }
+
     /// Get the ordinal representation of the date (days since 1975-01-01 BS)
     #[wasm_bindgen(js_name = toOrdinal)]
     pub fn to_ordinal(&self) -> i32 {
@@ -265,3 +301,28 @@ pub fn init() {
     #[cfg(feature = "console_error_panic_hook")]
     console_error_panic_hook::set_once();
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse() {
+        let date = NepaliDate::parse("19 Bhadra 2077", "%d %B %Y").unwrap();
+        assert_eq!(date.to_string(), "2077-05-19");
+    }
+
+    #[test]
+    fn test_weekday() {
+        let date = NepaliDate::new(2077, 5, 19).unwrap();
+        assert_eq!(date.weekday().unwrap(), "Friday");
+    }
+
+    #[test]
+    fn test_days_between() {
+        let a = NepaliDate::new(2077, 5, 19).unwrap();
+        let b = NepaliDate::from_ordinal(a.to_ordinal() + 10).unwrap();
+        assert_eq!(a.days_between(&b).unwrap(), 10);
+        assert_eq!(b.days_between(&a).unwrap(), -10);
+    }
+}
@@ -1,5 +1,5 @@
-use npdatetime::core::JulianDay;
-use npdatetime::lunar::tithi::TithiCalculator;
+use npdatetime::astronomical::core::JulianDay;
+use npdatetime::astronomical::lunar::tithi::TithiCalculator;
 
 fn main() {
     println!("Tithi Calculation for January 15, 2026...\n");
@@ -14,26 +14,37 @@ fn main() {
     // Find when this Tithi ends
     match TithiCalculator::find_tithi_end(tithi.index, jd) {
         Ok(end_jd) => {
-            let (y, m, d, h) = end_jd.to_gregorian();
-            println!("Tithi Ends at: {:04}-{:02}-{:02} {:02}:{:02} UTC", 
-                y, m, d, h as u32, ((h % 1.0) * 60.0) as u32);
+            let (y, m, d, h, min, _) = end_jd.to_gregorian_hms();
+            println!("Tithi Ends at: {:04}-{:02}-{:02} {:02}:{:02} UTC", y, m, d, h, min);
         },
         Err(e) => println!("Error finding Tithi end: {}", e),
     }
 
     // List upcoming Tithis
     println!("\nUpcoming Tithis:");
-    let mut current_jd = jd;
-    for _ in 0..5 {
-        let t = TithiCalculator::get_tithi(current_jd);
-        match TithiCalculator::find_tithi_end(t.index, current_jd) {
-            Ok(next_jd) => {
-                let (y, m, d, h) = next_jd.to_gregorian();
-                println!("{:<12} ends at {:04}-{:02}-{:02} {:02}:{:02} UTC", 
-                    t.name(), y, m, d, h as u32, ((h % 1.0) * 60.0) as u32);
-                current_jd = JulianDay(next_jd.0 + 0.1);
-            },
-            Err(_) => break,
-        }
+    match TithiCalculator::tithis_between(jd, jd.add_days(5.0)) {
+        Ok(periods) => {
+            for p in periods {
+                let (y, m, d, h, min, _) = p.end.to_gregorian_hms();
+                println!("{:<12} ends at {:04}-{:02}-{:02} {:02}:{:02} UTC{}",
+                    p.tithi.name(), y, m, d, h, min,
+                    if p.is_kshaya { " (kshaya)" } else { "" });
+            }
+        },
+        Err(e) => println!("Error listing Tithis: {}", e),
+    }
+
+    // List the whole lunar month from the preceding New Moon
+    println!("\nTithis of this lunar month:");
+    let new_moon = TithiCalculator::find_previous_tithi_end(0, jd).unwrap_or(jd);
+    match TithiCalculator::tithis_in_month(new_moon) {
+        Ok(month) => {
+            for (tithi, end) in month {
+                let (y, m, d, h, min, _) = end.to_gregorian_hms();
+                println!("{:<24} ends at {:04}-{:02}-{:02} {:02}:{:02} UTC",
+                    tithi.full_name(), y, m, d, h, min);
+            }
+        },
+        Err(e) => println!("Error listing the month: {}", e),
     }
 }
\ No newline at end of file
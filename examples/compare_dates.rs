@@ -48,7 +48,7 @@ fn main() {
             let civil = NepaliDate::from_gregorian(y, m, d).unwrap();
             let astro = BsDate::from_gregorian(y, m, d).unwrap();
 
-            let status = if civil.to_string() == astro.to_string() {
+            let status = if civil.matches_astronomical(&astro) {
                 "MATCH"
             } else {
                 "DIFF"
@@ -8,42 +8,29 @@ fn main() -> Result<()> {
 
     #[cfg(feature = "astronomical")]
     {
-        println!("Comparing Astronomical vs Lookup (1975 - 2100 BS)...");
-        let cal = AstronomicalCalendar::new();
-        let mut discrepancies = 0;
-        let mut total_months = 0;
-
-        for year in 1975..=2100 {
-            let info = cal
-                .get_year_info(year)
-                .map_err(NpdatetimeError::ParseError)?;
+        use npdatetime::lookup::verify_against_astronomical;
 
-            for month in 1..=12 {
-                total_months += 1;
-                let lookup_val = NepaliDate::days_in_month(year, month as u8)?;
-                let astro_val = info.month_lengths[month - 1];
-
-                if lookup_val != astro_val {
-                    discrepancies += 1;
-                    if discrepancies <= 10 {
-                        println!(
-                            "Discrepancy at {}-{:02}: Lookup={}, Astro={}",
-                            year, month, lookup_val, astro_val
-                        );
-                    }
-                }
-            }
+        println!("Comparing Astronomical vs Lookup (1975 - 2100 BS)...");
+        let years = 1975..=2100;
+        let total_months = (years.end() - years.start() + 1) * 12;
+        let discrepancies = verify_against_astronomical(years)?;
+
+        for d in discrepancies.iter().take(10) {
+            println!(
+                "Discrepancy at {}-{:02}: Lookup={}, Astro={}",
+                d.year, d.month, d.lookup, d.astro
+            );
         }
 
         println!("\nVerification Complete.");
         println!("Total Months Checked: {}", total_months);
-        println!("Total Discrepancies: {}", discrepancies);
+        println!("Total Discrepancies: {}", discrepancies.len());
         println!(
             "Accuracy: {:.2}%",
-            (1.0 - (discrepancies as f64 / total_months as f64)) * 100.0
+            (1.0 - (discrepancies.len() as f64 / total_months as f64)) * 100.0
         );
 
-        if discrepancies > 0 {
+        if !discrepancies.is_empty() {
             println!("\nNote: Minor discrepancies are expected due to floating-point precision ");
             println!("and different Lahiri Ayanamsha approximations used in various sources.");
         }
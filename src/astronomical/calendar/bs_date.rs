@@ -1,13 +1,18 @@
-use crate::astronomical::calendar::BsCalendar;
+use crate::astronomical::calendar::{BsCalendar, YearInfo};
 use crate::astronomical::core::JulianDay;
 use crate::astronomical::core::time::utc_to_npt;
+use crate::core::date::NEPALI_MONTHS;
 use crate::core::error::{NpdatetimeError, Result};
+use crate::core::format::{FormatSource, calculate_weekday, format_fields};
 use std::fmt;
 
-/// Represents a date in the astronomical Bikram Sambat calendar
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
+/// Ordinal 1: the NPT civil day of 1918-04-13 (Baisakh 1, 1975 BS in the civil calendar)
+const ORDINAL_EPOCH: (i32, u8, u8) = crate::core::date::BS_EPOCH_AD;
+
+/// Represents a date in the astronomical Bikram Sambat calendar
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct BsDate {
@@ -27,7 +32,10 @@ impl BsDate {
         }
 
         let cal = BsCalendar::new();
-        let max_day = cal.calculate_month_days(year, month);
+        let info = cal
+            .get_year_info(year)
+            .map_err(NpdatetimeError::CalculationError)?;
+        let max_day = info.month_lengths[month as usize - 1];
         if day < 1 || day > max_day {
             return Err(NpdatetimeError::InvalidDate(format!(
                 "Day must be between 1 and {}, got {}",
@@ -55,19 +63,20 @@ impl BsDate {
             SankrantiFinder::find_sankranti(0, JulianDay::from_gregorian(g_year, 4, 1, 0.0))
                 .map_err(NpdatetimeError::CalculationError)?;
 
-        let mut npt_mesh_jd = utc_to_npt(mesh_sankranti.julian_day);
+        let day_start = jd.start_of_day_npt();
+        let mut mesh_day_start = mesh_sankranti.julian_day.start_of_day_npt();
 
-        if npt_jd.0.floor() < npt_mesh_jd.0.floor() {
+        if day_start.0 < mesh_day_start.0 {
             bs_year -= 1;
             let prev_mesh = SankrantiFinder::find_sankranti(
                 0,
                 JulianDay::from_gregorian(g_year - 1, 4, 1, 0.0),
             )
             .map_err(NpdatetimeError::CalculationError)?;
-            npt_mesh_jd = utc_to_npt(prev_mesh.julian_day);
+            mesh_day_start = prev_mesh.julian_day.start_of_day_npt();
         }
 
-        let mut remaining_days = (npt_jd.0.floor() - npt_mesh_jd.0.floor()) as i64;
+        let mut remaining_days = (day_start.0 - mesh_day_start.0).round() as i64;
         let mut bs_month = 1u8;
 
         let info = cal
@@ -97,7 +106,62 @@ impl BsDate {
         })
     }
 
-    /// Convert BS Date to Julian Day (approximate to start of day in NPT)
+    /// Returns true if the date falls inside an Adhika (leap) lunar month
+    ///
+    /// This searches the year's lunations, so it is computed on demand rather
+    /// than when the date is created.
+    pub fn is_adhika(&self) -> Result<bool> {
+        Ok(self.adhika_month()?.is_some())
+    }
+
+    /// Formats the date like `Display`, appending the Adhika Masa it falls in
+    ///
+    /// e.g. `2077-06-15 (Adhika Ashwin)`; dates outside a leap month render
+    /// exactly as `Display` does.
+    pub fn format_with_leap(&self) -> Result<String> {
+        Ok(match self.adhika_month()? {
+            Some(month_index) => format!(
+                "{} (Adhika {})",
+                self,
+                NEPALI_MONTHS[month_index as usize - 1]
+            ),
+            None => self.to_string(),
+        })
+    }
+
+    /// Month index of the Adhika Masa containing this date, if any
+    fn adhika_month(&self) -> Result<Option<u8>> {
+        let info = BsCalendar::new()
+            .get_year_info(self.year)
+            .map_err(NpdatetimeError::CalculationError)?;
+        Ok(Self::adhika_month_at(&info, self.to_julian_day()?))
+    }
+
+    /// Formats the date using the same specifiers as [`crate::NepaliDate::format_date`]
+    ///
+    /// Weekday and week-of-year specifiers are resolved through the
+    /// astronomical calendar.
+    ///
+    /// # Examples:
+    /// ```
+    /// # use npdatetime::astronomical::BsDate;
+    /// let date = BsDate::new(2081, 1, 1).unwrap();
+    /// assert_eq!(date.format("%d %N %K"), "01 बैशाख २०८१");
+    /// assert_eq!(date.format("%D %N %K"), "०१ बैशाख २०८१");
+    /// ```
+    pub fn format(&self, format_str: &str) -> String {
+        format_fields(self, format_str, None)
+    }
+
+    /// Returns the month index of the year's Adhika Masa containing `jd`, if any
+    fn adhika_month_at(info: &YearInfo, jd: JulianDay) -> Option<u8> {
+        info.leap_months
+            .iter()
+            .find(|lm| lm.start_jd.0 <= jd.0 && jd.0 < lm.end_jd.0)
+            .map(|lm| lm.month_index)
+    }
+
+    /// Convert BS Date to Julian Day (local noon in NPT, expressed in UTC)
     pub fn to_julian_day(&self) -> Result<JulianDay> {
         use crate::astronomical::solar::sankranti::SankrantiFinder;
 
@@ -108,7 +172,7 @@ impl BsDate {
         )
         .map_err(NpdatetimeError::CalculationError)?;
 
-        let npt_mesh_jd = utc_to_npt(mesh_sankranti.julian_day);
+        let mesh_day_start = mesh_sankranti.julian_day.start_of_day_npt();
         let mut total_days = 0i64;
 
         let cal = BsCalendar::new();
@@ -122,12 +186,8 @@ impl BsDate {
 
         total_days += (self.day - 1) as i64;
 
-        // Start of the day in NPT
-        let jd_npt = JulianDay(npt_mesh_jd.0.floor() + total_days as f64 + 0.5); // Use midday for better round-tripping
-
-        // Convert back to UTC
-        use crate::astronomical::core::time::npt_to_utc;
-        Ok(npt_to_utc(jd_npt))
+        // Use local midday for better round-tripping
+        Ok(mesh_day_start.add_days(total_days as f64).noon_npt())
     }
 
     pub fn to_gregorian(&self) -> Result<(i32, u8, u8)> {
@@ -140,11 +200,76 @@ impl BsDate {
         let jd = JulianDay::from_gregorian(year, month, day, 12.0); // Midday
         Self::from_julian_day(jd)
     }
+
+    /// Adds days to the date, rolling months and years over via the astronomical year structure
+    pub fn add_days(&self, days: i32) -> Result<Self> {
+        let jd = self.to_julian_day()?;
+        Self::from_julian_day(jd.add_days(days as f64))
+    }
+
+    /// Returns the number of civil days since a fixed epoch JD
+    ///
+    /// The epoch is the NPT civil day of 1918-04-13 AD (ordinal 1), the same
+    /// day [`crate::NepaliDate::to_ordinal`] numbers as 1, so ordinals from
+    /// both calendars can be compared directly.
+    pub fn to_ordinal(&self) -> Result<i32> {
+        let day_start = self.to_julian_day()?.start_of_day_npt();
+        let days = day_start.diff_days(&Self::ordinal_epoch_start());
+        Ok(days.round() as i32 + 1)
+    }
+
+    /// Creates a date from an ordinal as returned by [`BsDate::to_ordinal`]
+    pub fn from_ordinal(ordinal: i32) -> Result<Self> {
+        let jd = Self::ordinal_epoch_start()
+            .add_days((ordinal - 1) as f64)
+            .noon_npt();
+        Self::from_julian_day(jd)
+    }
+
+    fn ordinal_epoch_start() -> JulianDay {
+        let (y, m, d) = ORDINAL_EPOCH;
+        JulianDay::from_gregorian(y, m, d, 12.0).start_of_day_npt()
+    }
+}
+
+impl FormatSource for BsDate {
+    fn ymd(&self) -> (i32, u8, u8) {
+        (self.year, self.month, self.day)
+    }
+
+    fn weekday_index(&self) -> Option<usize> {
+        let (y, m, d) = self.to_gregorian().ok()?;
+        Some(calculate_weekday(y, m, d))
+    }
+
+    fn year_position(&self) -> Option<(u16, usize)> {
+        let info = BsCalendar::new().get_year_info(self.year).ok()?;
+        let day_of_year = info.month_lengths[..self.month as usize - 1]
+            .iter()
+            .map(|&len| len as u16)
+            .sum::<u16>()
+            + self.day as u16;
+
+        let new_year = BsDate {
+            year: self.year,
+            month: 1,
+            day: 1,
+        };
+        Some((day_of_year, new_year.weekday_index()?))
+    }
 }
 
 impl fmt::Display for BsDate {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "{}-{:02}-{:02}", self.year, self.month, self.day)
+        // Catch sentinels such as 0000-00-00 in tests instead of printing them
+        debug_assert!(
+            (1..=12).contains(&self.month) && self.day >= 1,
+            "invalid date {}-{:02}-{:02}",
+            self.year,
+            self.month,
+            self.day
+        );
+        f.pad(&format!("{}-{:02}-{:02}", self.year, self.month, self.day))
     }
 }
 
@@ -152,6 +277,37 @@ impl fmt::Display for BsDate {
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_format_shares_specifiers() {
+        // Baisakh 1, 2081 is Saturday 2024-04-13 in both calendars
+        let date = BsDate::new(2081, 1, 1).unwrap();
+        assert_eq!(date.format("%d %N %K"), "01 बैशाख २०८१");
+        assert_eq!(date.format("%D %N %K"), "०१ बैशाख २०८१");
+        assert_eq!(
+            date.format("%A, %d %B %Y %P"),
+            "Shanibaar, 01 Baisakh 2081 B.S."
+        );
+        assert_eq!(date.format("%U %W"), "00 00");
+
+        #[cfg(feature = "lookup-tables")]
+        {
+            let civil = crate::NepaliDate::new(2081, 1, 1).unwrap();
+            assert_eq!(date.format("%a %G %U"), civil.format_date("%a %G %U"));
+        }
+    }
+
+    #[cfg(debug_assertions)]
+    #[test]
+    #[should_panic(expected = "invalid date 0-00-00")]
+    fn test_display_debug_asserts_sentinel() {
+        let sentinel = BsDate {
+            year: 0,
+            month: 0,
+            day: 0,
+        };
+        let _ = sentinel.to_string();
+    }
+
     #[test]
     fn test_bs_date_creation() {
         let date = BsDate::new(2081, 1, 1).unwrap();
@@ -168,6 +324,53 @@ mod tests {
         assert_eq!(original, round_trip);
     }
 
+    #[test]
+    fn test_add_days_across_month() {
+        let cal = BsCalendar::new();
+        let info = cal.get_year_info(2081).unwrap();
+        let baisakh = info.month_lengths[0];
+
+        let start = BsDate::new(2081, 1, 25).unwrap();
+        let later = start.add_days(32).unwrap();
+        assert_eq!(later, BsDate::new(2081, 2, 25 + 32 - baisakh).unwrap());
+        assert_eq!(later.add_days(-32).unwrap(), start);
+    }
+
+    #[test]
+    fn test_ordinal_round_trip() {
+        let start = BsDate::new(2081, 1, 25).unwrap();
+        let later = start.add_days(32).unwrap();
+        let ordinal = start.to_ordinal().unwrap();
+
+        assert_eq!(later.to_ordinal().unwrap() - ordinal, 32);
+        assert_eq!(BsDate::from_ordinal(ordinal).unwrap(), start);
+        assert!(start < later);
+    }
+
+    #[test]
+    fn test_ordinal_matches_civil_epoch() {
+        // Ordinals count the same civil days in both calendars
+        let date = BsDate::from_gregorian(1943, 4, 14).unwrap();
+        let civil = crate::NepaliDate::from_gregorian(1943, 4, 14).unwrap();
+        assert_eq!(date.to_ordinal().unwrap(), civil.to_ordinal());
+    }
+
+    #[test]
+    fn test_adhika_annotation() {
+        // 2077 BS has an Adhika Ashwin between the New Moons of 2020-09-17 and 2020-10-16
+        let date = BsDate::from_gregorian(2020, 10, 1).unwrap();
+        assert!(date.is_adhika().unwrap());
+        assert_eq!(date, BsDate::new(date.year, date.month, date.day).unwrap());
+        assert_eq!(
+            date.format_with_leap().unwrap(),
+            format!("{} (Adhika Ashwin)", date)
+        );
+
+        let regular = BsDate::from_gregorian(2020, 11, 1).unwrap();
+        assert!(!regular.is_adhika().unwrap());
+        assert_eq!(regular.format_with_leap().unwrap(), regular.to_string());
+    }
+
     #[test]
     fn test_specific_date_2081_baisakh_1() {
         // 2081 Baisakh 1 is 2024-04-13
@@ -3,7 +3,7 @@
 //! Identifies intercalary lunar months by checking for lunar months (New Moon to New Moon)
 //! that do not contain a solar transit (Sankranti).
 
-use crate::astronomical::core::JulianDay;
+use crate::astronomical::core::{JulianDay, ZodiacSign, constants::SYNODIC_MONTH};
 use crate::astronomical::lunar::tithi::TithiCalculator;
 use crate::astronomical::solar::sankranti::SankrantiFinder;
 
@@ -38,8 +38,10 @@ impl LeapMonthDetector {
         }
 
         // Also check the last month (Chaitra)
-        let next_mesh =
-            SankrantiFinder::find_sankranti(0, sankrantis[11].julian_day.add_days(25.0))?;
+        let next_mesh = SankrantiFinder::find_sankranti(
+            ZodiacSign::Pisces.next() as u8,
+            sankrantis[11].julian_day.add_days(25.0),
+        )?;
         if let Some(adhika) =
             Self::check_interval(12, sankrantis[11].julian_day, next_mesh.julian_day)?
         {
@@ -55,11 +57,12 @@ impl LeapMonthDetector {
         end_s: JulianDay,
     ) -> Result<Option<AdhikaMasa>, String> {
         // Find the first New Moon after start_s
-        let nm1 = TithiCalculator::find_next_new_moon(start_s)?;
+        let prev_nm = TithiCalculator::find_previous_tithi_end(0, start_s)?;
+        let nm1 = TithiCalculator::find_tithi_end(0, prev_nm.add_days(SYNODIC_MONTH))?;
 
         // If this New Moon is still before the next Sankranti, check the one after it
         if nm1.0 < end_s.0 {
-            let nm2 = TithiCalculator::find_next_new_moon(nm1)?;
+            let nm2 = TithiCalculator::find_tithi_end(0, nm1.add_days(SYNODIC_MONTH))?;
 
             // If the second New Moon is ALSO before the next Sankranti,
             // then the lunar month (nm1, nm2) is an Adhika Masa
@@ -75,3 +78,28 @@ impl LeapMonthDetector {
         Ok(None)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_adhika_shrawan_2080() {
+        // New Moons of 2023-07-17 and 2023-08-16 both fall inside the solar
+        // month of Shrawan, the first less than a day after Karka Sankranti
+        let adhika = LeapMonthDetector::find_adhika_masa(2080).unwrap();
+        assert_eq!(adhika.len(), 1);
+        assert_eq!(adhika[0].month_index, 4);
+
+        let (y, m, d, _) = adhika[0].start_jd.to_gregorian();
+        assert_eq!((y, m, d), (2023, 7, 17));
+        let (y, m, d, _) = adhika[0].end_jd.to_gregorian();
+        assert_eq!((y, m, d), (2023, 8, 16));
+
+        assert!(
+            LeapMonthDetector::find_adhika_masa(2081)
+                .unwrap()
+                .is_empty()
+        );
+    }
+}
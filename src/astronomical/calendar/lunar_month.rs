@@ -0,0 +1,147 @@
+//! Lunar month calculator
+//!
+//! Determines lunar month lengths for a BS year under the Amanta
+//! (New Moon to New Moon) and Purnimanta (Full Moon to Full Moon) schemes.
+//! A year with an Adhika Masa naturally yields 13 lunations.
+//!
+//! The two schemes share the Shukla paksha but name each Krishna paksha
+//! differently: Purnimanta months end a fortnight earlier, so the dark half
+//! belongs to the following month.
+
+use crate::astronomical::calendar::CalendarSystem;
+use crate::astronomical::core::{JulianDay, constants::SYNODIC_MONTH};
+use crate::astronomical::lunar::tithi::{Paksha, Tithi, TithiCalculator};
+use crate::astronomical::solar::sankranti::SankrantiFinder;
+use crate::core::month::NepaliMonth;
+
+pub struct LunarMonthCalculator;
+
+impl LunarMonthCalculator {
+    /// Calculate the boundaries (start of each lunar month plus the end of the
+    /// last one) for a given BS year
+    ///
+    /// The Amanta year starts at the New Moon preceding Mesh Sankranti; the
+    /// Purnimanta year starts at the Full Moon a fortnight earlier.
+    pub fn month_boundaries(
+        bs_year: i32,
+        system: CalendarSystem,
+    ) -> Result<Vec<JulianDay>, String> {
+        let target_index = match system {
+            CalendarSystem::SolarNepali => {
+                return Err("Solar calendar has no lunar month boundaries".to_string());
+            }
+            CalendarSystem::LunarAmanta => 0,
+            CalendarSystem::LunarPurnimanta => 15,
+        };
+
+        let this_mesh =
+            SankrantiFinder::find_sankranti(0, JulianDay::from_gregorian(bs_year - 57, 4, 1, 0.0))?;
+        let next_mesh =
+            SankrantiFinder::find_sankranti(0, JulianDay::from_gregorian(bs_year - 56, 4, 1, 0.0))?;
+
+        let mut start = TithiCalculator::find_previous_tithi_end(0, this_mesh.julian_day)?;
+        let mut end = TithiCalculator::find_previous_tithi_end(0, next_mesh.julian_day)?;
+        if target_index != 0 {
+            start = TithiCalculator::find_previous_tithi_end(target_index, start)?;
+            end = TithiCalculator::find_previous_tithi_end(target_index, end)?;
+        }
+
+        let mut boundaries = vec![start];
+        let mut current = start;
+        loop {
+            let next =
+                TithiCalculator::find_tithi_end(target_index, current.add_days(SYNODIC_MONTH))?;
+            if next.0 >= end.0 - 1.0 {
+                break;
+            }
+            boundaries.push(next);
+            current = next;
+        }
+        boundaries.push(end);
+
+        Ok(boundaries)
+    }
+
+    /// Calculate the lengths (in civil days, Nepal Local Time) of every lunar
+    /// month in a given BS year: 12 entries normally, 13 with an Adhika Masa
+    pub fn calculate_month_lengths(
+        bs_year: i32,
+        system: CalendarSystem,
+    ) -> Result<Vec<u8>, String> {
+        let boundaries = Self::month_boundaries(bs_year, system)?;
+
+        Ok(boundaries
+            .windows(2)
+            .map(|pair| {
+                let days = pair[1].start_of_day_npt().0 - pair[0].start_of_day_npt().0;
+                days.round() as u8
+            })
+            .collect())
+    }
+}
+
+/// Name of the lunar month a Tithi falls in under the given scheme
+///
+/// `amanta_month` is the month the Tithi belongs to in the Amanta
+/// (New Moon ending) reckoning. Under Purnimanta a Krishna paksha Tithi is
+/// counted in the next month, so e.g. Amanta Chaitra Krishna Ashtami is
+/// Purnimanta Baisakh Krishna Ashtami.
+pub fn lunar_month_name(
+    amanta_month: NepaliMonth,
+    tithi: &Tithi,
+    system: CalendarSystem,
+) -> Result<String, String> {
+    let month = match (system, tithi.paksha) {
+        (CalendarSystem::SolarNepali, _) => {
+            return Err("Solar calendar has no lunar month names".to_string());
+        }
+        (CalendarSystem::LunarPurnimanta, Paksha::Krishna) => {
+            NepaliMonth::from_number(amanta_month.number() % 12 + 1).unwrap_or(amanta_month)
+        }
+        _ => amanta_month,
+    };
+
+    Ok(month.name().to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn tithi(index: u8) -> Tithi {
+        Tithi::from_elongation((index as f64 - 0.5) * 12.0)
+    }
+
+    #[test]
+    fn test_krishna_paksha_named_differently() {
+        let ashtami = tithi(23);
+        assert_eq!(ashtami.paksha, Paksha::Krishna);
+        assert_eq!(
+            lunar_month_name(NepaliMonth::Chaitra, &ashtami, CalendarSystem::LunarAmanta).unwrap(),
+            "Chaitra"
+        );
+        assert_eq!(
+            lunar_month_name(
+                NepaliMonth::Chaitra,
+                &ashtami,
+                CalendarSystem::LunarPurnimanta
+            )
+            .unwrap(),
+            "Baisakh"
+        );
+    }
+
+    #[test]
+    fn test_shukla_paksha_named_the_same() {
+        let panchami = tithi(5);
+        for system in [CalendarSystem::LunarAmanta, CalendarSystem::LunarPurnimanta] {
+            assert_eq!(
+                lunar_month_name(NepaliMonth::Bhadra, &panchami, system).unwrap(),
+                "Bhadra"
+            );
+        }
+        assert!(
+            lunar_month_name(NepaliMonth::Bhadra, &panchami, CalendarSystem::SolarNepali).is_err()
+        );
+    }
+}
@@ -4,39 +4,147 @@
 
 pub mod bs_date;
 pub mod leap_month;
+pub mod lunar_month;
 pub mod month_calculator;
+pub mod nepal_sambat;
 pub mod synchronization;
 
 pub use bs_date::BsDate;
 pub use leap_month::{AdhikaMasa, LeapMonthDetector};
-pub use month_calculator::SolarMonthCalculator;
+pub use lunar_month::{LunarMonthCalculator, lunar_month_name};
+pub use month_calculator::{MonthStart, SolarMonthCalculator, month_start_dates};
 pub use synchronization::{CalendarSynchronizer, MonthDetail};
 
+/// Which month structure a [`BsCalendar`] computes
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum CalendarSystem {
+    /// Solar months bounded by Sankrantis (the civil Nepali calendar)
+    #[default]
+    SolarNepali,
+    /// Lunar months ending at New Moon
+    LunarAmanta,
+    /// Lunar months ending at Full Moon
+    LunarPurnimanta,
+}
+
 /// Information about a full Bikram Sambat year
+///
+/// Built by [`BsCalendar::get_year_info`].
 #[derive(Debug, Clone)]
+#[non_exhaustive]
 pub struct YearInfo {
     pub bs_year: i32,
-    /// Length of each month (12 entries)
+    /// Month structure these lengths were computed for
+    pub system: CalendarSystem,
+    /// Length of each month (12 entries, or 13 for a lunar year with an Adhika Masa)
     pub month_lengths: Vec<u8>,
     /// Any detected leap months in this year
     pub leap_months: Vec<AdhikaMasa>,
 }
 
+impl YearInfo {
+    /// Returns true if the year contains an Adhika (leap) lunar month
+    pub fn has_adhika_masa(&self) -> bool {
+        !self.leap_months.is_empty()
+    }
+
+    /// Total number of days across all months, including any Adhika Masa
+    pub fn total_days(&self) -> u32 {
+        self.month_lengths.iter().map(|&len| len as u32).sum()
+    }
+
+    /// Number of months: always 12 for solar years, 13 for a lunar year with an Adhika Masa
+    pub fn month_count(&self) -> u8 {
+        self.month_lengths.len() as u8
+    }
+}
+
+#[cfg(feature = "serde")]
+#[derive(serde::Serialize, serde::Deserialize)]
+struct YearInfoJson {
+    year: i32,
+    month_lengths: Vec<u8>,
+    leap_months: Vec<LeapMonthJson>,
+}
+
+#[cfg(feature = "serde")]
+#[derive(serde::Serialize, serde::Deserialize)]
+struct LeapMonthJson {
+    month_index: u8,
+    /// Gregorian (year, month, day) in Nepal Local Time
+    start: (i32, u8, u8),
+    end: (i32, u8, u8),
+}
+
+#[cfg(feature = "serde")]
+impl YearInfo {
+    /// Export the year structure as JSON for tooling outside Rust
+    ///
+    /// Produces `{ "year", "month_lengths", "leap_months" }`, where each leap
+    /// month carries its index and Gregorian start/end dates in NPT.
+    pub fn to_json(&self) -> Result<String, String> {
+        use crate::astronomical::core::time::utc_to_npt;
+
+        let civil_date = |jd| {
+            let (y, m, d, _) = utc_to_npt(jd).to_gregorian();
+            (y, m, d)
+        };
+
+        let json = YearInfoJson {
+            year: self.bs_year,
+            month_lengths: self.month_lengths.clone(),
+            leap_months: self
+                .leap_months
+                .iter()
+                .map(|lm| LeapMonthJson {
+                    month_index: lm.month_index,
+                    start: civil_date(lm.start_jd),
+                    end: civil_date(lm.end_jd),
+                })
+                .collect(),
+        };
+
+        serde_json::to_string(&json).map_err(|e| format!("JSON serialization failed: {}", e))
+    }
+}
+
 /// Main calendar calculator
-pub struct BsCalendar;
+///
+/// Create one with [`BsCalendar::new`], [`BsCalendar::with_system`] or
+/// `Default`.
+#[non_exhaustive]
+pub struct BsCalendar {
+    system: CalendarSystem,
+}
 
 impl BsCalendar {
     pub fn new() -> Self {
-        BsCalendar {}
+        BsCalendar {
+            system: CalendarSystem::SolarNepali,
+        }
+    }
+
+    /// Create a calculator for a specific month structure
+    pub fn with_system(system: CalendarSystem) -> Self {
+        BsCalendar { system }
+    }
+
+    /// The month structure this calculator uses
+    pub fn system(&self) -> CalendarSystem {
+        self.system
     }
 
     /// Get the structure of a given BS year
     pub fn get_year_info(&self, bs_year: i32) -> Result<YearInfo, String> {
-        let month_lengths = SolarMonthCalculator::calculate_month_lengths(bs_year)?;
+        let month_lengths = match self.system {
+            CalendarSystem::SolarNepali => SolarMonthCalculator::calculate_month_lengths(bs_year)?,
+            lunar => LunarMonthCalculator::calculate_month_lengths(bs_year, lunar)?,
+        };
         let leap_months = LeapMonthDetector::find_adhika_masa(bs_year)?;
 
         Ok(YearInfo {
             bs_year,
+            system: self.system,
             month_lengths,
             leap_months,
         })
@@ -81,6 +189,78 @@ mod tests {
         assert!(total_days == 365 || total_days == 366);
     }
 
+    #[test]
+    fn test_solar_system_is_default() {
+        let default_info = BsCalendar::new().get_year_info(2081).unwrap();
+        let solar_info = BsCalendar::with_system(CalendarSystem::SolarNepali)
+            .get_year_info(2081)
+            .unwrap();
+
+        assert_eq!(default_info.month_lengths, solar_info.month_lengths);
+        assert_eq!(solar_info.system, CalendarSystem::SolarNepali);
+    }
+
+    #[test]
+    fn test_lunar_amanta_adhika_year() {
+        // 2077 BS has Adhika Ashwin: 13 lunations
+        let cal = BsCalendar::with_system(CalendarSystem::LunarAmanta);
+        let info = cal.get_year_info(2077).unwrap();
+        assert_eq!(info.month_lengths.len(), 13);
+        assert!(info.month_lengths.iter().all(|&len| len == 29 || len == 30));
+        assert_eq!(info.leap_months.len(), 1);
+        assert_eq!(info.leap_months[0].month_index, 6);
+
+        // 2081 BS is a regular year: 12 lunations
+        let info = cal.get_year_info(2081).unwrap();
+        assert_eq!(info.month_lengths.len(), 12);
+    }
+
+    #[test]
+    fn test_year_info_summaries() {
+        let lunar = BsCalendar::with_system(CalendarSystem::LunarAmanta);
+
+        let adhika = lunar.get_year_info(2077).unwrap();
+        assert!(adhika.has_adhika_masa());
+        assert_eq!(adhika.month_count(), 13);
+        assert!((383..=385).contains(&adhika.total_days()));
+
+        let normal = lunar.get_year_info(2081).unwrap();
+        assert!(!normal.has_adhika_masa());
+        assert_eq!(normal.month_count(), 12);
+        assert!((353..=356).contains(&normal.total_days()));
+
+        // Solar years keep 12 months even when the lunar year has a leap month
+        let solar = BsCalendar::new().get_year_info(2077).unwrap();
+        assert!(solar.has_adhika_masa());
+        assert_eq!(solar.month_count(), 12);
+        assert!(solar.total_days() == 365 || solar.total_days() == 366);
+    }
+
+    #[test]
+    fn test_lunar_purnimanta_month_lengths() {
+        let cal = BsCalendar::with_system(CalendarSystem::LunarPurnimanta);
+        let info = cal.get_year_info(2081).unwrap();
+        assert_eq!(info.month_lengths.len(), 12);
+        let total: u32 = info.month_lengths.iter().map(|&x| x as u32).sum();
+        assert!((353..=356).contains(&total), "total = {}", total);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_year_info_json() {
+        let info = BsCalendar::new().get_year_info(2077).unwrap();
+        let json = info.to_json().unwrap();
+        let parsed: YearInfoJson = serde_json::from_str(&json).unwrap();
+
+        assert_eq!(parsed.year, 2077);
+        assert_eq!(parsed.month_lengths, info.month_lengths);
+        assert_eq!(parsed.leap_months.len(), 1);
+        // Adhika Ashwin: New Moon 2020-09-17 to New Moon 2020-10-17 (NPT)
+        assert_eq!(parsed.leap_months[0].month_index, 6);
+        assert_eq!(parsed.leap_months[0].start, (2020, 9, 17));
+        assert_eq!(parsed.leap_months[0].end, (2020, 10, 17));
+    }
+
     #[test]
     fn test_adhika_masa_2077() {
         let cal = BsCalendar::new();
@@ -3,7 +3,7 @@
 //! Determines month lengths by finding the Gregorian dates of consecutive
 //! Sankrantis in Nepal Local Time (UTC+5:45).
 
-use crate::astronomical::core::time::utc_to_npt;
+use crate::astronomical::core::time::civil_day_diff_npt;
 use crate::astronomical::solar::sankranti::SankrantiFinder;
 
 pub struct SolarMonthCalculator;
@@ -32,25 +32,64 @@ impl SolarMonthCalculator {
             let start_jd = all_sankrantis[i].julian_day;
             let end_jd = all_sankrantis[i + 1].julian_day;
 
-            // Convert JDs to Nepal Local Time and get Gregorian dates
-            let (start_y, start_m, start_d, _) = utc_to_npt(start_jd).to_gregorian();
-            let (end_y, end_m, end_d, _) = utc_to_npt(end_jd).to_gregorian();
-
-            // Calculate total days between these two Gregorian dates
-            let length =
-                Self::days_between_gregorian((start_y, start_m, start_d), (end_y, end_m, end_d));
-
-            lengths.push(length as u8);
+            // Count civil days between the two transits in Nepal Local Time
+            lengths.push(civil_day_diff_npt(start_jd, end_jd) as u8);
         }
 
         Ok(lengths)
     }
+}
+
+/// A BS month number and the Gregorian (year, month, day) it begins on
+pub type MonthStart = (u8, (i32, u8, u8));
+
+/// Gregorian date on which each month of a BS year begins
+///
+/// Each month starts on the civil day (Nepal Standard Time) of its
+/// Sankranti. Entries are `(month, (year, month, day))`, Baisakh first.
+///
+/// # Examples:
+/// ```
+/// use npdatetime::astronomical::month_start_dates;
+///
+/// let starts = month_start_dates(2081).unwrap();
+/// assert_eq!(starts[0], (1, (2024, 4, 13)));
+/// ```
+pub fn month_start_dates(bs_year: i32) -> Result<[MonthStart; 12], String> {
+    let sankrantis = SankrantiFinder::find_all_in_year(bs_year)?;
+
+    let mut starts = [(0, (0, 0, 0)); 12];
+    for (month, (start, sankranti)) in (1..=12).zip(starts.iter_mut().zip(&sankrantis)) {
+        let (y, m, d, _) = sankranti.local_datetime_npt();
+        *start = (month, (y, m, d));
+    }
 
-    /// Helper to calculate days between two Gregorian dates
-    fn days_between_gregorian(start: (i32, u8, u8), end: (i32, u8, u8)) -> i64 {
-        use crate::core::date::gregorian_to_days;
-        let start_days = gregorian_to_days(start.0, start.1, start.2);
-        let end_days = gregorian_to_days(end.0, end.1, end.2);
-        end_days - start_days
+    Ok(starts)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::date::gregorian_to_days;
+
+    #[test]
+    fn test_month_starts_match_lengths() {
+        let starts = month_start_dates(2081).unwrap();
+        let lengths = SolarMonthCalculator::calculate_month_lengths(2081).unwrap();
+
+        let mesh = SankrantiFinder::find_all_in_year(2081).unwrap()[0];
+        let (y, m, d, _) = mesh.local_datetime_npt();
+        assert_eq!(starts[0], (1, (y, m, d)));
+
+        let next_year = month_start_dates(2082).unwrap()[0].1;
+        for (i, &length) in lengths.iter().enumerate() {
+            let (y, m, d) = starts[i].1;
+            let (ny, nm, nd) = starts.get(i + 1).map_or(next_year, |s| s.1);
+            assert_eq!(starts[i].0 as usize, i + 1);
+            assert_eq!(
+                gregorian_to_days(ny, nm, nd) - gregorian_to_days(y, m, d),
+                length as i64
+            );
+        }
     }
 }
@@ -0,0 +1,178 @@
+//! Nepal Sambat, the lunisolar era of the Newar community
+//!
+//! A Nepal Sambat (NS) year starts on Kachhalā Shukla Pratipada, the day
+//! after the Amanta Kartik New Moon, and is numbered 879 behind the
+//! Gregorian year it starts in (NS 1145 began on 2024-11-02). Months are
+//! Amanta lunar months from Kachhalā (1) to Kaulā (12), and the day is the
+//! Tithi at sunrise: 1-15 in Shukla paksha, 16-30 in Krishna paksha.
+//!
+//! A leap month (Analā) carries the number of the month it precedes, and
+//! a Tithi spanning two sunrises repeats its day, so several BS dates can
+//! share one NS triple. The reverse conversion picks the first of them in
+//! the regular month.
+
+use crate::astronomical::core::{
+    JulianDay, ZodiacSign, constants::SYNODIC_MONTH, time::utc_to_npt,
+};
+use crate::astronomical::lunar::tithi::{APPROX_SUNRISE_NPT_HOURS, TithiCalculator};
+use crate::astronomical::solar::position::sun_rashi;
+use crate::core::date::NepaliDate;
+use crate::core::error::{NpdatetimeError, Result};
+
+/// Years from the start of the Nepal Sambat era (879 AD) to the Common Era
+const NS_AD_OFFSET: i32 = 879;
+
+/// Sun's rashi at the New Moon that opens Kachhalā (Amanta Kartik)
+const KACHHALA_RASHI: ZodiacSign = ZodiacSign::Libra;
+
+impl NepaliDate {
+    /// Converts the date to Nepal Sambat as (year, month, day)
+    ///
+    /// # Examples:
+    /// ```
+    /// # use npdatetime::NepaliDate;
+    /// // Mha Puja 2081, the first day of NS 1145
+    /// let date = NepaliDate::new(2081, 7, 17).unwrap();
+    /// assert_eq!(date.to_nepal_sambat().unwrap(), (1145, 1, 1));
+    /// ```
+    pub fn to_nepal_sambat(&self) -> Result<(i32, u8, u8)> {
+        let sunrise = self.sunrise()?;
+        let new_moon = TithiCalculator::find_previous_tithi_end(0, sunrise)
+            .map_err(NpdatetimeError::CalculationError)?;
+        let (month, _) = month_starting_at(new_moon)?;
+
+        // Kachhalā began in October or November, and a leap month in
+        // between only moves this estimate a lunation later
+        let year_start = new_moon.add_days(-((month - 1) as f64) * SYNODIC_MONTH);
+        let (start_year, _, _, _) = year_start.to_gregorian();
+        let day = TithiCalculator::get_tithi(sunrise).index;
+
+        Ok((start_year - NS_AD_OFFSET, month, day))
+    }
+
+    /// Creates a BS date from a Nepal Sambat (year, month, day)
+    ///
+    /// Returns the civil day on which that Tithi is current at sunrise, or
+    /// for a Tithi that touches no sunrise (kshaya), the day it runs on.
+    pub fn from_nepal_sambat(year: i32, month: u8, day: u8) -> Result<Self> {
+        if !(1..=12).contains(&month) || !(1..=30).contains(&day) {
+            return Err(NpdatetimeError::InvalidDate(format!(
+                "Invalid Nepal Sambat month {} or day {}",
+                month, day
+            )));
+        }
+        let calc = NpdatetimeError::CalculationError;
+
+        // Start from the New Moon nearest the month's mean position and
+        // step whole lunations until the regular month is reached
+        let approx = JulianDay::from_gregorian(year + NS_AD_OFFSET, 11, 1, 0.0)
+            .add_days((month - 1) as f64 * SYNODIC_MONTH);
+        let mut new_moon = TithiCalculator::find_previous_tithi_end(0, approx).map_err(calc)?;
+        for _ in 0..4 {
+            let (found, is_adhika) = month_starting_at(new_moon)?;
+            let offset = match (month as i32 - found as i32).rem_euclid(12) {
+                0 if is_adhika => 1,
+                0 => break,
+                n if n > 6 => n - 12,
+                n => n,
+            };
+            new_moon = TithiCalculator::find_tithi_end(
+                0,
+                new_moon.add_days(offset as f64 * SYNODIC_MONTH),
+            )
+            .map_err(calc)?;
+        }
+
+        let (y, m, d, _) = utc_to_npt(new_moon).to_gregorian();
+        let mut date = NepaliDate::from_gregorian(y, m, d)?;
+        // The day of the New Moon belongs to the previous month when its
+        // sunrise comes first
+        let mut index = if date.sunrise()?.0 < new_moon.0 {
+            0
+        } else {
+            TithiCalculator::get_tithi(date.sunrise()?).index
+        };
+
+        for _ in 0..32 {
+            let next = date.add_days(1)?;
+            let mut next_index = TithiCalculator::get_tithi(next.sunrise()?).index;
+            if next_index < index {
+                // Crossed into the following month
+                next_index = 31;
+            }
+            if index == day || (index < day && day < next_index) {
+                return Ok(date);
+            }
+            date = next;
+            index = next_index;
+        }
+
+        Err(NpdatetimeError::CalculationError(format!(
+            "No day {} in Nepal Sambat {}-{:02}",
+            day, year, month
+        )))
+    }
+
+    /// Approximate sunrise of this civil day in Nepal
+    fn sunrise(&self) -> Result<JulianDay> {
+        let (y, m, d) = self.to_gregorian()?;
+        Ok(JulianDay::from_gregorian(y, m, d, 0.0)
+            .start_of_day_npt()
+            .add_days(APPROX_SUNRISE_NPT_HOURS / 24.0))
+    }
+}
+
+/// Nepal Sambat month (1 = Kachhalā) opened by `new_moon`, and whether it
+/// is a leap month
+///
+/// A lunar month is named by the rashi the Sun enters during it; a month
+/// without a Sankranti is the leap month and takes the next month's name.
+fn month_starting_at(new_moon: JulianDay) -> Result<(u8, bool)> {
+    let next = TithiCalculator::find_tithi_end(0, new_moon.add_days(SYNODIC_MONTH))
+        .map_err(NpdatetimeError::CalculationError)?;
+    let sign = sun_rashi(new_moon);
+    let month = (sign as u8 + 12 - KACHHALA_RASHI as u8) % 12 + 1;
+    Ok((month, sun_rashi(next) == sign))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_new_year_days() {
+        // Mha Puja / Nhu Da, the first day of each NS year
+        let pairs = [
+            ((2080, 7, 28), 1144), // 2023-11-14
+            ((2081, 7, 17), 1145), // 2024-11-02
+            ((2082, 7, 5), 1146),  // 2025-10-22
+        ];
+        for ((y, m, d), ns_year) in pairs {
+            let date = NepaliDate::new(y, m, d).unwrap();
+            assert_eq!(date.to_nepal_sambat().unwrap(), (ns_year, 1, 1));
+            assert_eq!(NepaliDate::from_nepal_sambat(ns_year, 1, 1).unwrap(), date);
+
+            // Laxmi Puja, the day before, is the last day of the old year
+            let eve = date.add_days(-1).unwrap();
+            assert_eq!(eve.to_nepal_sambat().unwrap(), (ns_year - 1, 12, 30));
+        }
+    }
+
+    #[test]
+    fn test_round_trip_through_a_month() {
+        let start = NepaliDate::new(2081, 9, 1).unwrap();
+        for offset in 0..30 {
+            let date = start.add_days(offset).unwrap();
+            let (y, m, d) = date.to_nepal_sambat().unwrap();
+            let back = NepaliDate::from_nepal_sambat(y, m, d).unwrap();
+            // A Tithi spanning two sunrises maps back to its first day
+            assert!(back <= date && date.days_between(&back).unwrap() >= -1);
+        }
+    }
+
+    #[test]
+    fn test_invalid_components() {
+        assert!(NepaliDate::from_nepal_sambat(1145, 13, 1).is_err());
+        assert!(NepaliDate::from_nepal_sambat(1145, 1, 31).is_err());
+    }
+}
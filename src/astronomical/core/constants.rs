@@ -29,6 +29,9 @@ pub const SYNODIC_MONTH: f64 = 29.530588853;
 /// Tropical year (solar year in days)
 pub const TROPICAL_YEAR: f64 = 365.242189;
 
+/// Sun's mean motion along the ecliptic (degrees per day)
+pub const SUN_MEAN_DAILY_MOTION: f64 = FULL_CIRCLE / TROPICAL_YEAR;
+
 /// Sidereal month
 pub const SIDEREAL_MONTH: f64 = 27.321661;
 
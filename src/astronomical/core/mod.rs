@@ -7,7 +7,24 @@ pub mod newton_raphson;
 pub mod time;
 
 pub use newton_raphson::NewtonRaphsonSolver;
-pub use time::JulianDay;
+pub use time::{Ayanamsha, JulianDay};
+
+/// Settings shared by the astronomical calculators
+///
+/// Only sidereal (nirayana) positions such as Sankrantis depend on the
+/// ayanamsha; Tithis are defined by the Moon-Sun elongation, in which the
+/// ayanamsha cancels out.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct AstronomicalConfig {
+    pub ayanamsha: Ayanamsha,
+}
+
+impl AstronomicalConfig {
+    /// Creates a configuration using the given ayanamsha
+    pub fn with_ayanamsha(ayanamsha: Ayanamsha) -> Self {
+        AstronomicalConfig { ayanamsha }
+    }
+}
 
 /// Zodiac signs
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -27,13 +44,99 @@ pub enum ZodiacSign {
 }
 
 impl ZodiacSign {
+    /// All signs in order, starting with Aries (Mesh)
+    pub const ALL: [ZodiacSign; 12] = [
+        ZodiacSign::Aries,
+        ZodiacSign::Taurus,
+        ZodiacSign::Gemini,
+        ZodiacSign::Cancer,
+        ZodiacSign::Leo,
+        ZodiacSign::Virgo,
+        ZodiacSign::Libra,
+        ZodiacSign::Scorpio,
+        ZodiacSign::Sagittarius,
+        ZodiacSign::Capricorn,
+        ZodiacSign::Aquarius,
+        ZodiacSign::Pisces,
+    ];
+
+    /// The following sign, wrapping from Pisces (Meena) back to Aries (Mesh)
+    pub fn next(self) -> ZodiacSign {
+        Self::ALL[(self as usize + 1) % 12]
+    }
+
+    /// The preceding sign, wrapping from Aries (Mesh) back to Pisces (Meena)
+    pub fn prev(self) -> ZodiacSign {
+        Self::ALL[(self as usize + 11) % 12]
+    }
+
     /// Get longitude where this sign starts (in degrees)
     pub fn start_longitude(&self) -> f64 {
         (*self as u8 as f64) * 30.0
     }
 
-    /// Get BS month corresponding to this zodiac sign
+    /// Get the sign containing a sidereal (nirayana) longitude in degrees
+    ///
+    /// The longitude is normalized first, so negative values and values past
+    /// 360° are accepted.
+    pub fn from_longitude(longitude: f64) -> ZodiacSign {
+        let index = (longitude.rem_euclid(360.0) / 30.0).floor() as usize;
+        Self::ALL[index.min(11)]
+    }
+
+    /// Get BS month corresponding to this zodiac sign (Aries → 1 Baisakh, Pisces → 12 Chaitra)
     pub fn to_bs_month(&self) -> u8 {
-        (*self as u8 + 1) % 12 + 1
+        *self as u8 + 1
+    }
+
+    /// Get the zodiac sign whose Sankranti begins the given BS month
+    ///
+    /// Returns `None` for months outside 1-12.
+    pub fn from_bs_month(month: u8) -> Option<ZodiacSign> {
+        Self::ALL.get(month.checked_sub(1)? as usize).copied()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_bs_month_mapping() {
+        assert_eq!(ZodiacSign::Aries.to_bs_month(), 1);
+        assert_eq!(ZodiacSign::Pisces.to_bs_month(), 12);
+        assert_eq!(ZodiacSign::from_bs_month(1), Some(ZodiacSign::Aries));
+        assert_eq!(ZodiacSign::from_bs_month(12), Some(ZodiacSign::Pisces));
+        assert_eq!(ZodiacSign::from_bs_month(0), None);
+        assert_eq!(ZodiacSign::from_bs_month(13), None);
+
+        for sign in ZodiacSign::ALL {
+            assert_eq!(ZodiacSign::from_bs_month(sign.to_bs_month()), Some(sign));
+        }
+    }
+
+    #[test]
+    fn test_next_and_prev_wrap() {
+        assert_eq!(ZodiacSign::Pisces.next(), ZodiacSign::Aries);
+        assert_eq!(ZodiacSign::Aries.prev(), ZodiacSign::Pisces);
+
+        for start in ZodiacSign::ALL {
+            let mut sign = start;
+            for _ in 0..12 {
+                assert_eq!(sign.next().prev(), sign);
+                sign = sign.next();
+            }
+            assert_eq!(sign, start);
+        }
+    }
+
+    #[test]
+    fn test_from_longitude_boundaries() {
+        assert_eq!(ZodiacSign::from_longitude(0.0), ZodiacSign::Aries);
+        assert_eq!(ZodiacSign::from_longitude(29.9999), ZodiacSign::Aries);
+        assert_eq!(ZodiacSign::from_longitude(30.0), ZodiacSign::Taurus);
+        assert_eq!(ZodiacSign::from_longitude(359.9999), ZodiacSign::Pisces);
+        assert_eq!(ZodiacSign::from_longitude(-0.0001), ZodiacSign::Pisces);
+        assert_eq!(ZodiacSign::from_longitude(390.0), ZodiacSign::Taurus);
     }
 }
@@ -124,6 +124,53 @@ impl NewtonRaphsonSolver {
         self.solve(&f, &df, initial_guess)
     }
 
+    /// Find root using the secant method, starting from `x0` and `x1`
+    ///
+    /// Each iteration reuses the previous evaluation to estimate the slope,
+    /// so only one call to `f` is made per step instead of the three needed
+    /// by [`NewtonRaphsonSolver::solve_numerical`]. Worth it when `f` is
+    /// expensive, e.g. a full VSOP87/ELP-2000 evaluation.
+    pub fn solve_secant<F>(&self, f: F, x0: f64, x1: f64) -> Result<f64>
+    where
+        F: Fn(f64) -> f64,
+    {
+        let mut x_prev = x0;
+        let mut f_prev = f(x0);
+        if f_prev.abs() < self.tolerance {
+            return Ok(x0);
+        }
+
+        let mut x = x1;
+        for _iteration in 0..self.max_iterations {
+            let fx = f(x);
+
+            if fx.abs() < self.tolerance || x == x_prev {
+                return Ok(x);
+            }
+            if !fx.is_finite() {
+                return Err(NewtonRaphsonError::Diverged);
+            }
+
+            let slope = (fx - f_prev) / (x - x_prev);
+            if slope.abs() < self.min_derivative {
+                return Err(NewtonRaphsonError::ZeroDerivative { x });
+            }
+
+            x_prev = x;
+            f_prev = fx;
+            x -= fx / slope;
+
+            if !x.is_finite() {
+                return Err(NewtonRaphsonError::Diverged);
+            }
+        }
+
+        Err(NewtonRaphsonError::MaxIterationsReached {
+            iterations: self.max_iterations,
+            last_value: x,
+        })
+    }
+
     /// Find root for periodic functions (handles angle wrapping)
     pub fn solve_periodic<F, DF>(
         &self,
@@ -206,6 +253,15 @@ mod tests {
         assert!((result - 3.0).abs() < 1e-6);
     }
 
+    #[test]
+    fn test_secant() {
+        let f = |x: f64| x.cos() - x;
+
+        let solver = NewtonRaphsonSolver::default();
+        let result = solver.solve_secant(f, 0.5, 0.6).unwrap();
+        assert!((result - 0.7390851).abs() < 1e-6);
+    }
+
     #[test]
     fn test_periodic_function() {
         // Solve sin(x) = 0.5, expect x ≈ 30° (π/6 radians = 0.5236)
@@ -2,6 +2,7 @@
 //! Handles conversions between different time scales
 
 use super::constants::*;
+use std::ops::{Add, Sub};
 
 /// Julian Day Number
 #[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
@@ -60,6 +61,50 @@ impl JulianDay {
         (year, month as u8, day as u8, hour)
     }
 
+    /// Convert a Gregorian date and time of day (hours, minutes, seconds) to Julian Day
+    pub fn from_gregorian_hms(
+        year: i32,
+        month: u8,
+        day: u8,
+        hour: u8,
+        minute: u8,
+        second: u8,
+    ) -> Self {
+        let seconds = hour as f64 * 3600.0 + minute as f64 * 60.0 + second as f64;
+        Self::from_gregorian(year, month, day, seconds / 3600.0)
+    }
+
+    /// Convert to Gregorian date and time of day, rounded to the nearest second
+    ///
+    /// Rounding happens before the date is split off, so an instant a few
+    /// microseconds before midnight comes out as 00:00:00 of the next day
+    /// rather than 23:59:60.
+    pub fn to_gregorian_hms(&self) -> (i32, u8, u8, u8, u8, u8) {
+        let rounded = JulianDay(((self.0 + 0.5) * 86400.0).round() / 86400.0 - 0.5);
+        let (year, month, day, hour) = rounded.to_gregorian();
+        let seconds = ((hour * 3600.0).round() as u32).min(86399);
+        (
+            year,
+            month,
+            day,
+            (seconds / 3600) as u8,
+            (seconds / 60 % 60) as u8,
+            (seconds % 60) as u8,
+        )
+    }
+
+    /// Converts a UTC instant to Terrestrial Time by adding [`delta_t`]
+    ///
+    /// VSOP87 and ELP-2000 are evaluated in TT; calendar code works in UTC.
+    pub fn to_tt(&self) -> JulianDay {
+        self.add_days(delta_t(*self) / 86400.0)
+    }
+
+    /// Converts a Terrestrial Time instant back to UTC by subtracting [`delta_t`]
+    pub fn to_utc(&self) -> JulianDay {
+        self.add_days(-delta_t(*self) / 86400.0)
+    }
+
     /// Get Julian centuries since J2000.0
     pub fn centuries_since_j2000(&self) -> f64 {
         (self.0 - J2000_0) / DAYS_PER_CENTURY
@@ -74,6 +119,56 @@ impl JulianDay {
     pub fn diff_days(&self, other: &JulianDay) -> f64 {
         self.0 - other.0
     }
+
+    /// Start (00:00 NPT) of the Nepal civil day containing this instant, in UTC
+    ///
+    /// Julian Days begin at noon, so civil midnight sits at a `.5` fraction.
+    pub fn start_of_day_npt(&self) -> JulianDay {
+        let npt = utc_to_npt(*self);
+        npt_to_utc(JulianDay((npt.0 + 0.5).floor() - 0.5))
+    }
+
+    /// Local noon (12:00 NPT) of the Nepal civil day containing this instant, in UTC
+    pub fn noon_npt(&self) -> JulianDay {
+        self.start_of_day_npt().add_days(0.5)
+    }
+
+    /// Returns true if the two instants are within `tol_days` of each other
+    pub fn approx_eq(&self, other: &JulianDay, tol_days: f64) -> bool {
+        (self.0 - other.0).abs() <= tol_days
+    }
+
+    /// Round to the nearest whole minute
+    pub fn round_to_minute(&self) -> JulianDay {
+        JulianDay((self.0 * 1440.0).round() / 1440.0)
+    }
+}
+
+impl Add<f64> for JulianDay {
+    type Output = JulianDay;
+
+    /// Shifts the instant forward by a number of days
+    fn add(self, days: f64) -> JulianDay {
+        self.add_days(days)
+    }
+}
+
+impl Sub<f64> for JulianDay {
+    type Output = JulianDay;
+
+    /// Shifts the instant back by a number of days
+    fn sub(self, days: f64) -> JulianDay {
+        self.add_days(-days)
+    }
+}
+
+impl Sub<JulianDay> for JulianDay {
+    type Output = f64;
+
+    /// Difference in days, as [`JulianDay::diff_days`]
+    fn sub(self, other: JulianDay) -> f64 {
+        self.diff_days(&other)
+    }
 }
 
 /// Convert UTC to Nepal Time
@@ -86,17 +181,196 @@ pub fn npt_to_utc(jd: JulianDay) -> JulianDay {
     jd.add_days(-NEPAL_TZ_OFFSET / 24.0)
 }
 
+/// ΔT = TT − UT in seconds at the given instant
+///
+/// Uses the polynomial fits of Espenak & Meeus (2006), as published in the
+/// NASA Five Millennium Canon of Solar Eclipses, for 1900-2150, and their
+/// long-term parabola `-20 + 32u²` outside that span. The difference
+/// between UT1 and UTC (under a second) is ignored.
+pub fn delta_t(jd: JulianDay) -> f64 {
+    let y = 2000.0 + (jd.0 - J2000_0) / 365.25;
+
+    match y {
+        y if (1900.0..1920.0).contains(&y) => {
+            let t = y - 1900.0;
+            -2.79 + 1.494119 * t - 0.0598939 * t.powi(2) + 0.0061966 * t.powi(3)
+                - 0.000197 * t.powi(4)
+        }
+        y if (1920.0..1941.0).contains(&y) => {
+            let t = y - 1920.0;
+            21.20 + 0.84493 * t - 0.076100 * t.powi(2) + 0.0020936 * t.powi(3)
+        }
+        y if (1941.0..1961.0).contains(&y) => {
+            let t = y - 1950.0;
+            29.07 + 0.407 * t - t.powi(2) / 233.0 + t.powi(3) / 2547.0
+        }
+        y if (1961.0..1986.0).contains(&y) => {
+            let t = y - 1975.0;
+            45.45 + 1.067 * t - t.powi(2) / 260.0 - t.powi(3) / 718.0
+        }
+        y if (1986.0..2005.0).contains(&y) => {
+            let t = y - 2000.0;
+            63.86 + 0.3345 * t - 0.060374 * t.powi(2)
+                + 0.0017275 * t.powi(3)
+                + 0.000651814 * t.powi(4)
+                + 0.00002373599 * t.powi(5)
+        }
+        y if (2005.0..2050.0).contains(&y) => {
+            let t = y - 2000.0;
+            62.92 + 0.32217 * t + 0.005589 * t.powi(2)
+        }
+        y if (2050.0..2150.0).contains(&y) => {
+            let u = (y - 1820.0) / 100.0;
+            -20.0 + 32.0 * u * u - 0.5628 * (2150.0 - y)
+        }
+        y => {
+            let u = (y - 1820.0) / 100.0;
+            -20.0 + 32.0 * u * u
+        }
+    }
+}
+
+/// Number of NPT civil days from the day containing `start` to the day containing `end`
+///
+/// Both instants are rounded to the nearest second before being assigned
+/// to a day, so floating-point noise in a transit computed a hair before
+/// local midnight can't move it to the previous day.
+pub fn civil_day_diff_npt(start: JulianDay, end: JulianDay) -> i64 {
+    npt_day_number(end) - npt_day_number(start)
+}
+
+/// Index of the NPT civil day containing the instant, counting from JD 0
+fn npt_day_number(jd: JulianDay) -> i64 {
+    // Julian Days begin at noon, so shift by half a day to count from midnight
+    let seconds = ((utc_to_npt(jd).0 + 0.5) * 86400.0).round() as i64;
+    seconds.div_euclid(86400)
+}
+
 /// Ayanamsha (Chitra Paksha/Lahiri) approximation for Nirayana calculations
 pub fn get_ayanamsha(jd: JulianDay) -> f64 {
-    let t = jd.centuries_since_j2000();
-    // Lahiri Ayanamsha: 23° 51' 25.532" at J2000.0
-    23.857092 + 1.396971 * t + 0.000308 * t * t
+    Ayanamsha::Lahiri.value(jd)
+}
+
+/// Ayanamsha systems used by published panchangs
+///
+/// All variants share Lahiri's precession rate and differ by a constant
+/// offset at J2000.0, which is accurate to well under an arcminute over the
+/// supported range.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Ayanamsha {
+    /// Chitra Paksha, the official ayanamsha of the Indian and Nepali calendars
+    #[default]
+    Lahiri,
+    /// B. V. Raman (about 1°27' less than Lahiri)
+    Raman,
+    /// Krishnamurti Paddhati (about 6' less than Lahiri)
+    KrishnamurtiKP,
+    /// Fagan/Bradley western sidereal (about 53' more than Lahiri)
+    FaganBradley,
+}
+
+impl Ayanamsha {
+    /// Ayanamsha in degrees at the given instant
+    pub fn value(self, jd: JulianDay) -> f64 {
+        let t = jd.centuries_since_j2000();
+        // Lahiri Ayanamsha: 23° 51' 25.532" at J2000.0
+        let lahiri = 23.857092 + 1.396971 * t + 0.000308 * t * t;
+        lahiri + self.offset_from_lahiri()
+    }
+
+    /// Constant offset from Lahiri in degrees
+    fn offset_from_lahiri(self) -> f64 {
+        match self {
+            Ayanamsha::Lahiri => 0.0,
+            Ayanamsha::Raman => -1.4433,
+            Ayanamsha::KrishnamurtiKP => -0.0967,
+            Ayanamsha::FaganBradley => 0.8833,
+        }
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_civil_day_diff_around_npt_midnight() {
+        // 00:00 NPT on 2024-04-13 is 18:15 UTC the day before
+        let midnight = JulianDay::from_gregorian_hms(2024, 4, 12, 18, 15, 0);
+        let second = 1.0 / 86400.0;
+
+        assert_eq!(civil_day_diff_npt(midnight.add_days(-second), midnight), 1);
+        assert_eq!(civil_day_diff_npt(midnight, midnight.add_days(second)), 0);
+
+        // Sub-millisecond noise on either side of midnight stays on the new day
+        let noise = 1e-9;
+        assert_eq!(
+            civil_day_diff_npt(midnight.add_days(-noise), midnight.add_days(noise)),
+            0
+        );
+
+        // A month from just before midnight to just after it spans one extra civil day
+        let end = midnight.add_days(31.0);
+        assert_eq!(
+            civil_day_diff_npt(midnight.add_days(-second), end.add_days(second)),
+            32
+        );
+        assert_eq!(
+            civil_day_diff_npt(midnight.add_days(second), end.add_days(-second)),
+            30
+        );
+        assert_eq!(civil_day_diff_npt(end, midnight), -31);
+    }
+
+    #[test]
+    fn test_delta_t_model() {
+        // Observed values: ~+24 s (1940), ~+63.8 s (2000), ~+69.4 s (2020);
+        // the 2005-2050 extrapolation runs a couple of seconds high
+        let at = |year| delta_t(JulianDay::from_gregorian(year, 1, 1, 0.0));
+        assert!((at(1940) - 24.3).abs() < 1.0, "{}", at(1940));
+        assert!((at(2000) - 63.8).abs() < 0.5, "{}", at(2000));
+        assert!((at(2020) - 69.4).abs() < 3.0, "{}", at(2020));
+        assert!(at(2100) > at(2050));
+
+        let utc = JulianDay::from_gregorian(2024, 4, 13, 0.0);
+        let shift = (utc.to_tt() - utc) * 86400.0;
+        assert!((shift - delta_t(utc)).abs() < 1e-3);
+        assert!(utc.to_tt().to_utc().approx_eq(&utc, 1e-9));
+    }
+
+    #[test]
+    fn test_arithmetic_operators() {
+        let jd = JulianDay(J2000_0);
+        assert_eq!(jd + 1.5, JulianDay(J2000_0 + 1.5));
+        assert_eq!(jd - 0.25, JulianDay(J2000_0 - 0.25));
+        assert_eq!((jd + 10.0) - jd, 10.0);
+        assert_eq!(jd - (jd + 10.0), -10.0);
+        assert_eq!(jd + 2.0 - 2.0, jd);
+    }
+
+    #[test]
+    fn test_approx_eq() {
+        let jd = JulianDay(J2000_0);
+        let minute = 1.0 / 1440.0;
+        assert!(jd.approx_eq(&(jd + 0.5 * minute), minute));
+        assert!((jd - 0.5 * minute).approx_eq(&jd, minute));
+        assert!(!jd.approx_eq(&(jd + 2.0 * minute), minute));
+        assert!(jd.approx_eq(&jd, 0.0));
+    }
+
+    #[test]
+    fn test_hms_round_trip() {
+        let jd = JulianDay::from_gregorian_hms(2024, 4, 13, 17, 42, 37);
+        assert_eq!(jd.to_gregorian_hms(), (2024, 4, 13, 17, 42, 37));
+
+        let jd = JulianDay::from_gregorian_hms(2026, 1, 15, 0, 0, 1);
+        assert_eq!(jd.to_gregorian_hms(), (2026, 1, 15, 0, 0, 1));
+
+        // Just before midnight rounds into the next day instead of overflowing
+        let late = JulianDay::from_gregorian_hms(2024, 12, 31, 23, 59, 59).add_days(0.9 / 86400.0);
+        assert_eq!(late.to_gregorian_hms(), (2025, 1, 1, 0, 0, 0));
+    }
+
     #[test]
     fn test_gregorian_to_julian() {
         // January 1, 2000, 12:00 = JD 2451545.0
@@ -104,6 +378,43 @@ mod tests {
         assert!((jd.0 - J2000_0).abs() < 0.0001);
     }
 
+    #[test]
+    fn test_start_of_day_npt() {
+        // 2024-04-13 20:00 UTC is 2024-04-14 01:45 NPT
+        let jd = JulianDay::from_gregorian(2024, 4, 13, 20.0);
+        let start = utc_to_npt(jd.start_of_day_npt());
+        let (y, m, d, h) = start.to_gregorian();
+        assert_eq!((y, m, d), (2024, 4, 14));
+        assert!(h.abs() < 1e-6);
+
+        // Idempotent and never after the input
+        assert_eq!(
+            jd.start_of_day_npt(),
+            jd.start_of_day_npt().start_of_day_npt()
+        );
+        assert!(jd.start_of_day_npt().0 <= jd.0);
+    }
+
+    #[test]
+    fn test_noon_npt() {
+        // 2024-04-13 03:00 UTC is 08:45 NPT on the same civil day
+        let jd = JulianDay::from_gregorian(2024, 4, 13, 3.0);
+        let (y, m, d, h) = utc_to_npt(jd.noon_npt()).to_gregorian();
+        assert_eq!((y, m, d), (2024, 4, 13));
+        assert!((h - 12.0).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_round_to_minute() {
+        // 10:15:29 rounds down, 10:15:31 rounds up
+        let down = JulianDay::from_gregorian(2024, 1, 1, 10.0 + 15.0 / 60.0 + 29.0 / 3600.0);
+        let up = JulianDay::from_gregorian(2024, 1, 1, 10.0 + 15.0 / 60.0 + 31.0 / 3600.0);
+        let (_, _, _, h) = down.round_to_minute().to_gregorian();
+        assert!((h * 60.0 - 615.0).abs() < 1e-6);
+        let (_, _, _, h) = up.round_to_minute().to_gregorian();
+        assert!((h * 60.0 - 616.0).abs() < 1e-6);
+    }
+
     #[test]
     fn test_julian_to_gregorian() {
         let jd = JulianDay(J2000_0);
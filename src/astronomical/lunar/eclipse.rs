@@ -0,0 +1,68 @@
+//! Eclipse possibility detection
+//!
+//! An eclipse can only happen at a New or Full Moon that falls close to one
+//! of the Moon's nodes. This module applies the gross test from Meeus,
+//! *Astronomical Algorithms* ch. 54 (`|sin F| <= 0.36` at the syzygy) and
+//! does not compute magnitude, type or visibility.
+
+use super::elp2000::Elp2000Calculator;
+use super::tithi::TithiCalculator;
+use crate::astronomical::core::{
+    JulianDay,
+    constants::{DEG_TO_RAD, SYNODIC_MONTH},
+};
+
+/// Above this value of |sin F| at a syzygy no eclipse is possible
+const ECLIPSE_LIMIT_SIN_F: f64 = 0.36;
+
+/// Which body is eclipsed
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EclipseKind {
+    /// The Moon hides the Sun, at a New Moon
+    Solar,
+    /// The Earth's shadow falls on the Moon, at a Full Moon
+    Lunar,
+}
+
+/// Checks whether the New or Full Moon nearest to `jd` (UTC) can produce an eclipse
+///
+/// Returns the kind of eclipse that is possible, or `None` when that
+/// syzygy falls too far from a lunar node.
+pub fn is_eclipse_possible(jd: JulianDay) -> Option<EclipseKind> {
+    let elongation = TithiCalculator::get_tithi(jd).elongation;
+    let (kind, target_index, target_elongation) = if (90.0..270.0).contains(&elongation) {
+        (EclipseKind::Lunar, 15, 180.0)
+    } else {
+        (EclipseKind::Solar, 0, 0.0)
+    };
+
+    let ahead = ((target_elongation - elongation + 180.0).rem_euclid(360.0)) - 180.0;
+    let approx = jd.add_days(ahead * SYNODIC_MONTH / 360.0);
+    let syzygy = TithiCalculator::find_tithi_end(target_index, approx).unwrap_or(approx);
+
+    let f = Elp2000Calculator::argument_of_latitude(syzygy.to_tt());
+    ((f * DEG_TO_RAD).sin().abs() <= ECLIPSE_LIMIT_SIN_F).then_some(kind)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_known_eclipses() {
+        // Total solar eclipse of 2024-04-08, checked from a day earlier
+        let solar = JulianDay::from_gregorian(2024, 4, 7, 12.0);
+        assert_eq!(is_eclipse_possible(solar), Some(EclipseKind::Solar));
+
+        // Total lunar eclipse of 2025-03-14
+        let lunar = JulianDay::from_gregorian(2025, 3, 14, 6.0);
+        assert_eq!(is_eclipse_possible(lunar), Some(EclipseKind::Lunar));
+    }
+
+    #[test]
+    fn test_syzygy_far_from_node() {
+        // New Moon of 2024-06-06 falls midway between eclipse seasons
+        let jd = JulianDay::from_gregorian(2024, 6, 6, 12.0);
+        assert_eq!(is_eclipse_possible(jd), None);
+    }
+}
@@ -5,6 +5,9 @@
 //!
 //! This implementation uses the fundamental arguments and most significant
 //! periodic terms to provide accuracy suitable for Tithi and eclipse calculations.
+//!
+//! The theory is defined in Terrestrial Time (TT); only the apparent
+//! longitude accepts UTC and applies delta-T itself.
 
 use crate::astronomical::core::{JulianDay, constants::*};
 
@@ -176,8 +179,18 @@ impl Elp2000Calculator {
         385000.56 + delta_r
     }
 
+    /// Moon's mean argument of latitude F, its angular distance from the
+    /// ascending node (in degrees, 0-360)
+    pub fn argument_of_latitude(jd: JulianDay) -> f64 {
+        FundamentalArgs::calculate(jd).f
+    }
+
     /// Calculate Moon's apparent longitude (includes nutation)
+    ///
+    /// Unlike the geometric methods above, which take Terrestrial Time, `jd`
+    /// here is UTC and is converted with [`JulianDay::to_tt`].
     pub fn apparent_longitude(jd: JulianDay) -> f64 {
+        let jd = jd.to_tt();
         let geo_long = Self::geocentric_longitude(jd);
 
         // Nutation in longitude (simplified, same as solar)
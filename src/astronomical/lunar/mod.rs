@@ -2,11 +2,16 @@
 //!
 //! Calculates Moon's position and Tithi
 
+pub mod eclipse;
 pub mod elp2000;
+pub mod panchanga;
 pub mod phases;
 pub mod position;
 pub mod tithi;
 
+pub use eclipse::{EclipseKind, is_eclipse_possible};
 pub use elp2000::Elp2000Calculator;
-pub use tithi::{Paksha, Tithi, TithiCalculator};
+pub use panchanga::{DayTransitions, LimbTransition, Panchanga};
+pub use phases::{illumination_percent, phase_angle};
+pub use tithi::{Paksha, SearchDirection, Tithi, TithiCalculator, TithiPeriod};
 // pub use phases::MoonPhase;
@@ -0,0 +1,375 @@
+//! Panchanga (the five limbs of the Hindu almanac)
+//!
+//! Tithi, Vara, Nakshatra, Yoga and Karana at a given instant. Nakshatra and
+//! Yoga use sidereal (Lahiri) longitudes; Tithi and Karana depend only on the
+//! Moon-Sun elongation. The Sun and Moon rashis are included alongside.
+
+use super::elp2000::Elp2000Calculator;
+use super::tithi::{APPROX_SUNRISE_NPT_HOURS, SearchDirection, Tithi, TithiCalculator};
+use crate::astronomical::core::{
+    JulianDay, ZodiacSign,
+    constants::{FULL_CIRCLE, SIDEREAL_MONTH, SUN_MEAN_DAILY_MOTION},
+    newton_raphson::NewtonRaphsonSolver,
+    time::{get_ayanamsha, utc_to_npt},
+};
+use crate::astronomical::solar::vsop87::Vsop87Calculator;
+use crate::core::format::calculate_weekday;
+use crate::core::weekday::Weekday;
+use std::fmt;
+
+/// Nakshatra (lunar mansion) names in order
+pub const NAKSHATRA_NAMES: [&str; 27] = [
+    "Ashwini",
+    "Bharani",
+    "Krittika",
+    "Rohini",
+    "Mrigashira",
+    "Ardra",
+    "Punarvasu",
+    "Pushya",
+    "Ashlesha",
+    "Magha",
+    "Purva Phalguni",
+    "Uttara Phalguni",
+    "Hasta",
+    "Chitra",
+    "Swati",
+    "Vishakha",
+    "Anuradha",
+    "Jyeshtha",
+    "Mula",
+    "Purva Ashadha",
+    "Uttara Ashadha",
+    "Shravana",
+    "Dhanishta",
+    "Shatabhisha",
+    "Purva Bhadrapada",
+    "Uttara Bhadrapada",
+    "Revati",
+];
+
+/// Yoga names in order
+pub const YOGA_NAMES: [&str; 27] = [
+    "Vishkambha",
+    "Priti",
+    "Ayushman",
+    "Saubhagya",
+    "Shobhana",
+    "Atiganda",
+    "Sukarma",
+    "Dhriti",
+    "Shula",
+    "Ganda",
+    "Vriddhi",
+    "Dhruva",
+    "Vyaghata",
+    "Harshana",
+    "Vajra",
+    "Siddhi",
+    "Vyatipata",
+    "Variyana",
+    "Parigha",
+    "Shiva",
+    "Siddha",
+    "Sadhya",
+    "Shubha",
+    "Shukla",
+    "Brahma",
+    "Indra",
+    "Vaidhriti",
+];
+
+/// The seven movable Karanas, repeated eight times from the second half of Shukla Pratipada
+const MOVABLE_KARANAS: [&str; 7] = [
+    "Bava", "Balava", "Kaulava", "Taitila", "Garaja", "Vanija", "Vishti",
+];
+
+/// Arc of one Nakshatra or Yoga (13°20')
+const NAKSHATRA_DEGREES: f64 = FULL_CIRCLE / 27.0;
+
+/// The five limbs of the almanac at one instant
+#[derive(Debug, Clone, Copy)]
+pub struct Panchanga {
+    pub tithi: Tithi,
+    /// Weekday of the NPT civil day
+    pub vara: Weekday,
+    /// Nakshatra index (1 = Ashwini, 27 = Revati)
+    pub nakshatra: u8,
+    /// Yoga index (1 = Vishkambha, 27 = Vaidhriti)
+    pub yoga: u8,
+    /// Karana index (1-60, two per Tithi)
+    pub karana: u8,
+    /// Sign of the Moon's nirayana longitude
+    pub moon_rashi: ZodiacSign,
+    /// Sign of the Sun's nirayana longitude
+    pub sun_rashi: ZodiacSign,
+}
+
+/// A limb's value and the moment it gives way to the next one
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct LimbTransition {
+    /// Index of the prevailing Tithi, Nakshatra or Yoga
+    pub index: u8,
+    /// End of that Tithi, Nakshatra or Yoga, in UTC
+    pub end: JulianDay,
+}
+
+impl LimbTransition {
+    /// Gregorian date and fractional hour of the end in Nepal Standard Time
+    pub fn end_npt(&self) -> (i32, u8, u8, f64) {
+        utc_to_npt(self.end).to_gregorian()
+    }
+}
+
+/// When the Tithi, Nakshatra and Yoga prevailing at sunrise end, as returned
+/// by [`Panchanga::transitions`]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct DayTransitions {
+    pub tithi: LimbTransition,
+    pub nakshatra: LimbTransition,
+    pub yoga: LimbTransition,
+}
+
+impl Panchanga {
+    /// Calculates the Panchanga at a given Julian Day (UTC)
+    pub fn at(jd: JulianDay) -> Self {
+        let sun_long = Vsop87Calculator::sun_apparent_longitude(jd);
+        let moon_long = Elp2000Calculator::apparent_longitude(jd);
+        let ayanamsha = get_ayanamsha(jd);
+
+        let elongation = (moon_long - sun_long).rem_euclid(FULL_CIRCLE);
+        let moon_sidereal = (moon_long - ayanamsha).rem_euclid(FULL_CIRCLE);
+        let sun_sidereal = (sun_long - ayanamsha).rem_euclid(FULL_CIRCLE);
+        let yoga_long = (sun_sidereal + moon_sidereal).rem_euclid(FULL_CIRCLE);
+
+        let (year, month, day, _) = utc_to_npt(jd).to_gregorian();
+        let vara = Weekday::from_index(calculate_weekday(year, month, day) as u8)
+            .unwrap_or(Weekday::Sunday);
+
+        Self {
+            tithi: Tithi::from_elongation(elongation),
+            vara,
+            nakshatra: Self::segment(moon_sidereal, NAKSHATRA_DEGREES, 27),
+            yoga: Self::segment(yoga_long, NAKSHATRA_DEGREES, 27),
+            karana: Self::segment(elongation, 6.0, 60),
+            moon_rashi: ZodiacSign::from_longitude(moon_sidereal),
+            sun_rashi: ZodiacSign::from_longitude(sun_sidereal),
+        }
+    }
+
+    /// Finds when the Tithi, Nakshatra and Yoga of the NPT civil day
+    /// containing `jd` end
+    ///
+    /// The values are those prevailing at sunrise (approximated as 06:00
+    /// NPT), as a traditional almanac lists them. Each end lies later that
+    /// day or, for a limb spanning the whole day, early on the next.
+    ///
+    /// # Examples:
+    /// ```
+    /// use npdatetime::astronomical::Panchanga;
+    /// use npdatetime::astronomical::core::JulianDay;
+    ///
+    /// let today = Panchanga::transitions(JulianDay::from_gregorian(2024, 4, 13, 0.5)).unwrap();
+    /// let (_, _, _, hour) = today.tithi.end_npt();
+    /// println!("Tithi {} ends at {:.2} h NPT", today.tithi.index, hour);
+    /// ```
+    pub fn transitions(jd: JulianDay) -> Result<DayTransitions, String> {
+        let sunrise = jd
+            .start_of_day_npt()
+            .add_days(APPROX_SUNRISE_NPT_HOURS / 24.0);
+        let p = Self::at(sunrise);
+
+        let tithi_end = TithiCalculator::find_tithi_end_directed(
+            p.tithi.index,
+            sunrise,
+            SearchDirection::Forward,
+        )?;
+
+        let moon_motion = FULL_CIRCLE / SIDEREAL_MONTH;
+        let nakshatra_end = find_crossing(
+            |jd| sidereal_longitudes(jd).1,
+            p.nakshatra as f64 * NAKSHATRA_DEGREES,
+            sunrise,
+            moon_motion,
+        )?;
+        let yoga_end = find_crossing(
+            |jd| {
+                let (sun, moon) = sidereal_longitudes(jd);
+                sun + moon
+            },
+            p.yoga as f64 * NAKSHATRA_DEGREES,
+            sunrise,
+            moon_motion + SUN_MEAN_DAILY_MOTION,
+        )?;
+
+        Ok(DayTransitions {
+            tithi: LimbTransition {
+                index: p.tithi.index,
+                end: tithi_end,
+            },
+            nakshatra: LimbTransition {
+                index: p.nakshatra,
+                end: nakshatra_end,
+            },
+            yoga: LimbTransition {
+                index: p.yoga,
+                end: yoga_end,
+            },
+        })
+    }
+
+    /// 1-based index of the arc of `width` degrees containing `longitude`
+    fn segment(longitude: f64, width: f64, count: u8) -> u8 {
+        ((longitude / width).floor() as u8 + 1).min(count)
+    }
+
+    pub fn nakshatra_name(&self) -> &'static str {
+        NAKSHATRA_NAMES[self.nakshatra as usize - 1]
+    }
+
+    pub fn yoga_name(&self) -> &'static str {
+        YOGA_NAMES[self.yoga as usize - 1]
+    }
+
+    /// Name of the Karana; the four fixed Karanas fall around the New Moon
+    pub fn karana_name(&self) -> &'static str {
+        match self.karana {
+            1 => "Kimstughna",
+            58 => "Shakuni",
+            59 => "Chatushpada",
+            60 => "Naga",
+            k => MOVABLE_KARANAS[(k as usize - 2) % 7],
+        }
+    }
+}
+
+/// Nirayana (Lahiri) longitudes of the Sun and Moon, in degrees
+fn sidereal_longitudes(jd: JulianDay) -> (f64, f64) {
+    let ayanamsha = get_ayanamsha(jd);
+    let sun = Vsop87Calculator::sun_apparent_longitude(jd) - ayanamsha;
+    let moon = Elp2000Calculator::apparent_longitude(jd) - ayanamsha;
+    (sun.rem_euclid(FULL_CIRCLE), moon.rem_euclid(FULL_CIRCLE))
+}
+
+/// First moment after `from` at which `longitude` reaches `target` degrees
+///
+/// The initial guess comes from the mean `daily_motion`, so the solver starts
+/// within hours of the root.
+fn find_crossing(
+    longitude: impl Fn(JulianDay) -> f64,
+    target: f64,
+    from: JulianDay,
+    daily_motion: f64,
+) -> Result<JulianDay, String> {
+    let ahead = (target - longitude(from)).rem_euclid(FULL_CIRCLE);
+    let approx = from.0 + ahead / daily_motion;
+
+    // Normalize to [-180, 180] for root finding
+    let f = |jd: f64| (longitude(JulianDay(jd)) - target + 180.0).rem_euclid(FULL_CIRCLE) - 180.0;
+
+    let solver = NewtonRaphsonSolver::new(50, 1e-8);
+    match solver.solve_secant(f, approx, approx + 0.01) {
+        Ok(jd) => Ok(JulianDay(jd)),
+        Err(e) => Err(format!("Newton-Raphson failed: {:?}", e)),
+    }
+}
+
+impl fmt::Display for Panchanga {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.pad(&format!(
+            "Tithi: {}, Vara: {}, Nakshatra: {}, Yoga: {}, Karana: {}",
+            self.tithi,
+            self.vara,
+            self.nakshatra_name(),
+            self.yoga_name(),
+            self.karana_name()
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_karana_names() {
+        let mut p = Panchanga::at(JulianDay::from_gregorian(2024, 4, 13, 0.5));
+        for (karana, name) in [
+            (1, "Kimstughna"),
+            (2, "Bava"),
+            (8, "Vishti"),
+            (9, "Bava"),
+            (57, "Vishti"),
+            (58, "Shakuni"),
+            (60, "Naga"),
+        ] {
+            p.karana = karana;
+            assert_eq!(p.karana_name(), name);
+        }
+    }
+
+    #[test]
+    fn test_transitions() {
+        let jd = JulianDay::from_gregorian(2024, 4, 13, 0.5);
+        let sunrise = jd
+            .start_of_day_npt()
+            .add_days(APPROX_SUNRISE_NPT_HOURS / 24.0);
+        let day = Panchanga::transitions(jd).unwrap();
+        let minute = 1.0 / 1440.0;
+
+        assert_eq!(day.tithi.index, 5);
+        let tithi_end = TithiCalculator::find_tithi_end(5, sunrise).unwrap();
+        assert!((day.tithi.end.0 - tithi_end.0).abs() < minute);
+
+        // Each limb changes within a minute either side of its end
+        let around = |limb: LimbTransition| {
+            assert!(limb.end.0 > sunrise.0 && limb.end.0 < sunrise.0 + 2.0);
+            (
+                Panchanga::at(limb.end.add_days(-minute)),
+                Panchanga::at(limb.end.add_days(minute)),
+            )
+        };
+        let (before, after) = around(day.tithi);
+        assert_eq!(before.tithi.index, day.tithi.index);
+        assert_ne!(after.tithi.index, day.tithi.index);
+        let (before, after) = around(day.nakshatra);
+        assert_eq!(before.nakshatra, day.nakshatra.index);
+        assert_ne!(after.nakshatra, day.nakshatra.index);
+        let (before, after) = around(day.yoga);
+        assert_eq!(before.yoga, day.yoga.index);
+        assert_ne!(after.yoga, day.yoga.index);
+
+        let (y, m, d, _) = day.tithi.end_npt();
+        assert_eq!((y, m, d), (2024, 4, 13));
+    }
+
+    #[test]
+    fn test_limbs_are_consistent() {
+        // 2024-04-13 06:00 NPT: Chaitra Shukla Panchami, a Saturday
+        let jd = JulianDay::from_gregorian(2024, 4, 13, 0.25);
+        let p = Panchanga::at(jd);
+
+        assert_eq!(p.tithi.index, 5);
+        assert_eq!(p.vara, Weekday::Saturday);
+        assert!(p.karana == 2 * p.tithi.index - 1 || p.karana == 2 * p.tithi.index);
+        assert!((1..=27).contains(&p.nakshatra));
+        assert!((1..=27).contains(&p.yoga));
+        assert_eq!(
+            p.sun_rashi,
+            crate::astronomical::solar::position::sun_rashi(jd)
+        );
+        assert_eq!(
+            p.moon_rashi,
+            crate::astronomical::lunar::position::moon_rashi(jd)
+        );
+        assert_eq!(
+            p.to_string(),
+            format!(
+                "Tithi: Shukla Panchami (5), Vara: Saturday, Nakshatra: {}, Yoga: {}, Karana: {}",
+                p.nakshatra_name(),
+                p.yoga_name(),
+                p.karana_name()
+            )
+        );
+    }
+}
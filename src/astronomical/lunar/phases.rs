@@ -1 +1,52 @@
-// Phases of the Moon
+//! Phases of the Moon
+//!
+//! Both quantities derive from the Sun-Moon elongation used for Tithi. The
+//! illuminated fraction ignores the Moon's ecliptic latitude, which keeps it
+//! within a fraction of a percent except close to eclipses.
+
+use super::tithi::TithiCalculator;
+use crate::astronomical::core::{JulianDay, constants::DEG_TO_RAD};
+
+/// Moon's elongation east of the Sun in degrees (0-360)
+///
+/// 0 at New Moon, 90 at First Quarter, 180 at Full Moon and 270 at Last
+/// Quarter, so waxing and waning phases can be told apart.
+pub fn phase_angle(jd: JulianDay) -> f64 {
+    TithiCalculator::get_tithi(jd).elongation
+}
+
+/// Illuminated part of the Moon's disk as a percentage (0-100)
+///
+/// # Examples:
+/// ```
+/// use npdatetime::astronomical::core::JulianDay;
+/// use npdatetime::astronomical::lunar::illumination_percent;
+///
+/// // Full Moon of 2024-04-23 23:49 UTC
+/// let jd = JulianDay::from_gregorian(2024, 4, 23, 23.8);
+/// assert!(illumination_percent(jd) > 99.9);
+/// ```
+pub fn illumination_percent(jd: JulianDay) -> f64 {
+    (1.0 - (phase_angle(jd) * DEG_TO_RAD).cos()) / 2.0 * 100.0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_new_and_full_moon() {
+        // New Moon of 2024-01-11 11:57 UTC and Full Moon of 2024-01-25 17:54 UTC
+        let new_moon = JulianDay::from_gregorian(2024, 1, 11, 11.95);
+        let full_moon = JulianDay::from_gregorian(2024, 1, 25, 17.9);
+
+        assert!(illumination_percent(new_moon) < 0.1);
+        assert!(illumination_percent(full_moon) > 99.9);
+        assert!((phase_angle(full_moon) - 180.0).abs() < 0.5);
+
+        // First Quarter of 2024-01-18 03:53 UTC
+        let first_quarter = JulianDay::from_gregorian(2024, 1, 18, 3.9);
+        assert!((phase_angle(first_quarter) - 90.0).abs() < 0.5);
+        assert!((illumination_percent(first_quarter) - 50.0).abs() < 1.0);
+    }
+}
@@ -5,7 +5,7 @@
 pub use super::elp2000::Elp2000Calculator as MoonCalculator;
 
 // Convenience functions
-use crate::astronomical::core::JulianDay;
+use crate::astronomical::core::{JulianDay, ZodiacSign, time::get_ayanamsha};
 
 /// Calculate Moon's geocentric longitude (in degrees)
 pub fn moon_longitude(jd: JulianDay) -> f64 {
@@ -21,3 +21,31 @@ pub fn moon_apparent_longitude(jd: JulianDay) -> f64 {
 pub fn moon_distance(jd: JulianDay) -> f64 {
     super::elp2000::Elp2000Calculator::distance(jd)
 }
+
+/// Calculate the Moon's rashi (sign of its nirayana longitude, Lahiri ayanamsha)
+pub fn moon_rashi(jd: JulianDay) -> ZodiacSign {
+    ZodiacSign::from_longitude(moon_apparent_longitude(jd) - get_ayanamsha(jd))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_moon_rashi_at_sign_boundary() {
+        // Walk to the instant the Moon's nirayana longitude next reaches a multiple of 30°
+        let sidereal =
+            |jd: JulianDay| (moon_apparent_longitude(jd) - get_ayanamsha(jd)).rem_euclid(360.0);
+        let mut jd = JulianDay::from_gregorian(2024, 4, 13, 0.0);
+        let boundary = ((sidereal(jd) / 30.0).floor() + 1.0) * 30.0;
+        for _ in 0..5 {
+            let gap = (boundary - sidereal(jd) + 180.0).rem_euclid(360.0) - 180.0;
+            jd = jd.add_days(gap / 13.2);
+        }
+
+        let before = moon_rashi(jd.add_days(-10.0 / 1440.0));
+        let after = moon_rashi(jd.add_days(10.0 / 1440.0));
+        assert_eq!(after, ZodiacSign::from_longitude(boundary));
+        assert_eq!(before, ZodiacSign::from_longitude(boundary - 30.0));
+    }
+}
@@ -4,7 +4,11 @@
 //! Each Tithi corresponds to 12° of increasing elongation.
 
 use super::elp2000::Elp2000Calculator;
-use crate::astronomical::core::{JulianDay, newton_raphson::NewtonRaphsonSolver};
+use crate::astronomical::core::{
+    JulianDay,
+    constants::{FULL_CIRCLE, SYNODIC_MONTH, TITHI_DEGREES},
+    newton_raphson::NewtonRaphsonSolver,
+};
 use crate::astronomical::solar::vsop87::Vsop87Calculator;
 
 /// Tithi names in order
@@ -48,6 +52,17 @@ pub enum Paksha {
     Krishna, // Waning (Dark)
 }
 
+impl Paksha {
+    /// Paksha of a Tithi index (1-15 Shukla, 16-30 Krishna)
+    pub fn from_tithi_index(index: u8) -> Self {
+        if index <= 15 {
+            Paksha::Shukla
+        } else {
+            Paksha::Krishna
+        }
+    }
+}
+
 impl std::fmt::Display for Paksha {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
@@ -70,24 +85,78 @@ impl Tithi {
         TITHI_NAMES[self.index as usize - 1]
     }
 
+    /// Day within the paksha (1-15); Purnima and Amavasya are both day 15
+    pub fn paksha_day(&self) -> u8 {
+        (self.index - 1) % 15 + 1
+    }
+
+    /// Paksha and name together, as printed on calendars (e.g. "Krishna Ashtami")
+    pub fn full_name(&self) -> String {
+        format!("{} {}", self.paksha, self.name())
+    }
+
     /// Create Tithi from elongation (0 to 360)
     pub fn from_elongation(elongation: f64) -> Self {
         let elongation = elongation.rem_euclid(360.0);
-        let index = (elongation / 12.0).floor() as u8 + 1;
-        let paksha = if index <= 15 {
-            Paksha::Shukla
-        } else {
-            Paksha::Krishna
-        };
+        let index = ((elongation / 12.0).floor() as u8 + 1).min(30);
 
         Self {
-            index: index.min(30),
-            paksha,
+            index,
+            paksha: Paksha::from_tithi_index(index),
             elongation,
         }
     }
 }
 
+impl std::fmt::Display for Tithi {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.pad(&format!("{} ({})", self.full_name(), self.index))
+    }
+}
+
+/// Tithis order by elongation, i.e. by their position in the lunar month
+impl PartialEq for Tithi {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == std::cmp::Ordering::Equal
+    }
+}
+
+impl Eq for Tithi {}
+
+impl PartialOrd for Tithi {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Tithi {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        self.elongation.total_cmp(&other.elongation)
+    }
+}
+
+/// Direction in which to search for a Tithi boundary
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SearchDirection {
+    /// First boundary strictly after the reference instant
+    Forward,
+    /// Last boundary at or before the reference instant
+    Backward,
+}
+
+/// A Tithi together with the moments it starts and ends
+#[derive(Debug, Clone, Copy)]
+pub struct TithiPeriod {
+    pub tithi: Tithi,
+    pub start: JulianDay,
+    pub end: JulianDay,
+    /// The Tithi touches no sunrise, so it is skipped (kshaya) in the civil calendar
+    pub is_kshaya: bool,
+}
+
+/// Approximate local sunrise used for kshaya detection (hours after NPT midnight)
+pub(crate) const APPROX_SUNRISE_NPT_HOURS: f64 = 6.0;
+
 pub struct TithiCalculator;
 
 impl TithiCalculator {
@@ -113,13 +182,158 @@ impl TithiCalculator {
             diff
         };
 
+        // Secant steps reuse the previous Sun/Moon evaluation instead of
+        // sampling both sides of each point for a numerical derivative
         let solver = NewtonRaphsonSolver::new(50, 1e-8);
-        match solver.solve_numerical(f, approx_jd.0, 0.001) {
+        match solver.solve_secant(f, approx_jd.0, approx_jd.0 + 0.01) {
             Ok(jd_end) => Ok(JulianDay(jd_end)),
             Err(e) => Err(format!("Newton-Raphson failed: {:?}", e)),
         }
     }
 
+    /// Find when a Tithi begins and ends
+    ///
+    /// The end is the occurrence of the Tithi nearest `approx_jd`, as with
+    /// [`TithiCalculator::find_tithi_end`]; the start is the preceding 12°
+    /// boundary. Both are in UTC.
+    pub fn tithi_span(index: u8, approx_jd: JulianDay) -> Result<(JulianDay, JulianDay), String> {
+        if !(1..=30).contains(&index) {
+            return Err(format!(
+                "Tithi index must be between 1 and 30, got {}",
+                index
+            ));
+        }
+
+        let end = Self::find_tithi_end(index, approx_jd)?;
+        let approx_start = end.add_days(-TITHI_DEGREES * SYNODIC_MONTH / FULL_CIRCLE);
+        let start = Self::find_tithi_end(index - 1, approx_start)?;
+        if start.0 >= end.0 {
+            return Err(format!("Tithi {} start did not precede its end", index));
+        }
+
+        Ok((start, end))
+    }
+
+    /// Find the end of a specific Tithi searching in the given direction from `jd`
+    pub fn find_tithi_end_directed(
+        target_index: u8,
+        jd: JulianDay,
+        direction: SearchDirection,
+    ) -> Result<JulianDay, String> {
+        match direction {
+            SearchDirection::Backward => Self::find_previous_tithi_end(target_index, jd),
+            SearchDirection::Forward => {
+                let target_elongation = (target_index as f64) * 12.0;
+                let elongation = Self::get_tithi(jd).elongation;
+                let ahead = (target_elongation - elongation).rem_euclid(360.0);
+                let approx = jd.add_days(ahead * SYNODIC_MONTH / 360.0);
+
+                let found = Self::find_tithi_end(target_index, approx)?;
+                if found.0 <= jd.0 {
+                    Self::find_tithi_end(target_index, found.add_days(SYNODIC_MONTH))
+                } else {
+                    Ok(found)
+                }
+            }
+        }
+    }
+
+    /// List every Tithi ending between `start` and `end`, in order
+    ///
+    /// Each consecutive Tithi is found from the previous one's end, so the
+    /// index always advances by exactly one. A Tithi that begins and ends
+    /// between two consecutive sunrises is flagged as kshaya. Sunrise is
+    /// approximated as 06:00 NPT.
+    pub fn tithis_between(start: JulianDay, end: JulianDay) -> Result<Vec<TithiPeriod>, String> {
+        let mut results = Vec::new();
+        let mut current = Self::get_tithi(start);
+        let mut begin =
+            Self::find_tithi_end_directed(current.index - 1, start, SearchDirection::Backward)?;
+
+        loop {
+            // The elongation at `begin` is known exactly, so guess the end from
+            // the mean motion instead of evaluating the Sun and Moon again
+            let approx = begin.add_days(TITHI_DEGREES * SYNODIC_MONTH / FULL_CIRCLE);
+            let mut finish = Self::find_tithi_end(current.index, approx)?;
+            if finish.0 <= begin.0 {
+                finish =
+                    Self::find_tithi_end_directed(current.index, begin, SearchDirection::Forward)?;
+            }
+            if finish.0 > end.0 {
+                break;
+            }
+
+            results.push(TithiPeriod {
+                tithi: current,
+                start: begin,
+                end: finish,
+                is_kshaya: last_sunrise_at_or_before(finish).0 < begin.0,
+            });
+
+            begin = finish;
+            current = Tithi::from_elongation(current.index as f64 * TITHI_DEGREES);
+        }
+
+        Ok(results)
+    }
+
+    /// List the 30 Tithis of the lunar month beginning at the New Moon
+    /// nearest `start_jd`, each with the moment it ends
+    ///
+    /// The month runs from that New Moon to the next, so the list goes from
+    /// Shukla Pratipada to Amavasya. Every Tithi has exactly one end, even
+    /// when it is kshaya or adhika in the civil calendar, so a complete month
+    /// always yields 30 entries. Use [`TithiCalculator::tithis_between`] for
+    /// the start moments and kshaya flags.
+    pub fn tithis_in_month(start_jd: JulianDay) -> Result<Vec<(Tithi, JulianDay)>, String> {
+        let new_moon = Self::find_tithi_end(0, start_jd)?;
+        let next_new_moon = Self::find_next_new_moon(new_moon)?;
+
+        // Half a Tithi past each New Moon is well inside Pratipada, keeping
+        // the boundaries themselves away from the search limits
+        let margin = TITHI_DEGREES * SYNODIC_MONTH / FULL_CIRCLE / 2.0;
+        let periods =
+            Self::tithis_between(new_moon.add_days(margin), next_new_moon.add_days(margin))?;
+
+        if periods.len() != 30 || periods[0].tithi.index != 1 {
+            return Err(format!(
+                "Expected 30 Tithis from Pratipada, found {}",
+                periods.len()
+            ));
+        }
+
+        Ok(periods.into_iter().map(|p| (p.tithi, p.end)).collect())
+    }
+
+    /// Find the most recent end of a specific Tithi at or before `jd`
+    ///
+    /// The initial guess is derived from the current elongation and the mean
+    /// synodic motion, so the solver starts within a day or so of the root.
+    pub fn find_previous_tithi_end(target_index: u8, jd: JulianDay) -> Result<JulianDay, String> {
+        let target_elongation = (target_index as f64) * 12.0;
+        let elongation = Self::get_tithi(jd).elongation;
+        let behind = (elongation - target_elongation).rem_euclid(360.0);
+        let approx = jd.add_days(-behind * SYNODIC_MONTH / 360.0);
+
+        let found = Self::find_tithi_end(target_index, approx)?;
+        if found.0 > jd.0 {
+            Self::find_tithi_end(target_index, found.add_days(-SYNODIC_MONTH))
+        } else {
+            Ok(found)
+        }
+    }
+
+    /// Get the Tithi prevailing at sunrise of the NPT civil day containing `jd`
+    ///
+    /// This is the Tithi a traditional calendar assigns to the whole day.
+    /// Sunrise is approximated as 06:00 NPT.
+    pub fn tithi_at_sunrise(jd: JulianDay) -> Tithi {
+        Self::get_tithi(
+            jd.start_of_day_npt()
+                .add_days(APPROX_SUNRISE_NPT_HOURS / 24.0),
+        )
+    }
+
     /// Find the next New Moon (Amavasya end) after the given Julian Day
     pub fn find_next_new_moon(jd: JulianDay) -> Result<JulianDay, String> {
         // A lunar month is approximately 29.53 days.
@@ -128,3 +342,176 @@ impl TithiCalculator {
         Self::find_tithi_end(0, JulianDay(jd.0 + 25.0))
     }
 }
+
+/// Approximate sunrise at or before `jd`
+fn last_sunrise_at_or_before(jd: JulianDay) -> JulianDay {
+    let sunrise = jd
+        .start_of_day_npt()
+        .add_days(APPROX_SUNRISE_NPT_HOURS / 24.0);
+    if sunrise.0 > jd.0 {
+        sunrise.add_days(-1.0)
+    } else {
+        sunrise
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_tithis_order_by_elongation() {
+        let mut tithis: Vec<Tithi> = [200.0, 5.0, 170.0, 359.0]
+            .into_iter()
+            .map(Tithi::from_elongation)
+            .collect();
+        tithis.sort();
+        let indices: Vec<u8> = tithis.iter().map(|t| t.index).collect();
+        assert_eq!(indices, vec![1, 15, 17, 30]);
+        assert_eq!(Tithi::from_elongation(370.0), Tithi::from_elongation(10.0));
+    }
+
+    #[test]
+    fn test_tithi_span() {
+        let approx = JulianDay::from_gregorian(2024, 4, 13, 12.0);
+        for index in [1, 5, 15, 16, 30] {
+            let (start, end) = TithiCalculator::tithi_span(index, approx).unwrap();
+            let hours = (end - start) * 24.0;
+            assert!(
+                (19.0..=27.0).contains(&hours),
+                "Tithi {}: {} h",
+                index,
+                hours
+            );
+
+            let middle = start + (end - start) / 2.0;
+            assert_eq!(TithiCalculator::get_tithi(middle).index, index);
+        }
+
+        assert!(TithiCalculator::tithi_span(0, approx).is_err());
+        assert!(TithiCalculator::tithi_span(31, approx).is_err());
+    }
+
+    #[test]
+    fn test_paksha_day_and_full_name() {
+        let krishna_panchami = Tithi::from_elongation(19.0 * 12.0 + 1.0);
+        assert_eq!(krishna_panchami.index, 20);
+        assert_eq!(krishna_panchami.paksha_day(), 5);
+        assert_eq!(krishna_panchami.full_name(), "Krishna Panchami");
+
+        let purnima = Tithi::from_elongation(14.0 * 12.0 + 1.0);
+        assert_eq!(purnima.index, 15);
+        assert_eq!(purnima.paksha_day(), 15);
+        assert_eq!(purnima.full_name(), "Shukla Purnima");
+
+        let pratipada = Tithi::from_elongation(1.0);
+        assert_eq!(pratipada.paksha_day(), 1);
+        assert_eq!(Tithi::from_elongation(359.0).paksha_day(), 15);
+    }
+
+    #[test]
+    fn test_tithi_display() {
+        assert_eq!(
+            Tithi::from_elongation(50.0).to_string(),
+            "Shukla Panchami (5)"
+        );
+        assert_eq!(
+            Tithi::from_elongation(355.0).to_string(),
+            "Krishna Amavasya (30)"
+        );
+    }
+
+    #[test]
+    fn test_find_previous_new_moon() {
+        // New Moon of 2020-09-17 11:00 UTC
+        let jd = JulianDay::from_gregorian(2020, 9, 20, 0.0);
+        let nm = TithiCalculator::find_previous_tithi_end(0, jd).unwrap();
+        let (y, m, d, _) = nm.to_gregorian();
+        assert_eq!((y, m, d), (2020, 9, 17));
+        assert!(nm.0 <= jd.0);
+    }
+
+    #[test]
+    fn test_secant_matches_numerical_newton() {
+        let start = JulianDay::from_gregorian(2024, 1, 1, 0.0);
+        let periods = TithiCalculator::tithis_between(start, start.add_days(60.0)).unwrap();
+        assert!(periods.len() > 55);
+
+        for period in periods {
+            let target = (period.tithi.index as f64) * 12.0;
+            let f = |jd: f64| {
+                let diff = TithiCalculator::get_tithi(JulianDay(jd)).elongation - target;
+                (diff + 180.0).rem_euclid(360.0) - 180.0
+            };
+            let newton = NewtonRaphsonSolver::new(50, 1e-8)
+                .solve_numerical(f, period.end.0 - 0.3, 0.001)
+                .unwrap();
+            assert!(
+                (newton - period.end.0).abs() < 1e-6,
+                "Tithi {} end differs by {} days",
+                period.tithi.index,
+                newton - period.end.0
+            );
+        }
+    }
+
+    #[test]
+    fn test_find_tithi_end_directed() {
+        let jd = JulianDay::from_gregorian(2020, 9, 20, 0.0);
+        let forward =
+            TithiCalculator::find_tithi_end_directed(0, jd, SearchDirection::Forward).unwrap();
+        let backward =
+            TithiCalculator::find_tithi_end_directed(0, jd, SearchDirection::Backward).unwrap();
+
+        assert!(backward.0 <= jd.0 && jd.0 < forward.0);
+        let (y, m, d, _) = forward.to_gregorian();
+        assert_eq!((y, m, d), (2020, 10, 16));
+    }
+
+    #[test]
+    fn test_tithis_between_detects_kshaya() {
+        let start = JulianDay::from_gregorian(2024, 1, 11, 0.0);
+        let end = JulianDay::from_gregorian(2024, 2, 9, 0.0);
+        let periods = TithiCalculator::tithis_between(start, end).unwrap();
+
+        for pair in periods.windows(2) {
+            assert_eq!(pair[1].tithi.index, pair[0].tithi.index % 30 + 1);
+            assert_eq!(pair[0].end, pair[1].start);
+        }
+
+        // Shukla Chaturthi runs 2024-01-14 08:18 to 01-15 05:17 NPT, missing both sunrises
+        let chaturthi = periods
+            .iter()
+            .find(|p| p.tithi.index == 4)
+            .expect("Shukla Chaturthi in range");
+        assert!(chaturthi.is_kshaya);
+        assert_eq!(periods.iter().filter(|p| p.is_kshaya).count(), 1);
+    }
+
+    #[test]
+    fn test_tithis_in_month() {
+        // New Moon of 2024-01-11 11:57 UTC to that of 2024-02-09 22:59 UTC
+        let start = JulianDay::from_gregorian(2024, 1, 11, 0.0);
+        let month = TithiCalculator::tithis_in_month(start).unwrap();
+
+        assert_eq!(month.len(), 30);
+        for (i, (tithi, _)) in month.iter().enumerate() {
+            assert_eq!(tithi.index as usize, i + 1);
+        }
+        assert!(month.windows(2).all(|w| w[0].1.0 < w[1].1.0));
+
+        let (y, m, d, _) = month[0].1.to_gregorian();
+        assert_eq!((y, m, d), (2024, 1, 12));
+        let (y, m, d, _) = month[29].1.to_gregorian();
+        assert_eq!((y, m, d), (2024, 2, 9));
+    }
+
+    #[test]
+    fn test_find_previous_full_moon() {
+        // Full Moon of 2020-10-01 21:05 UTC
+        let jd = JulianDay::from_gregorian(2020, 10, 10, 0.0);
+        let fm = TithiCalculator::find_previous_tithi_end(15, jd).unwrap();
+        let (y, m, d, _) = fm.to_gregorian();
+        assert_eq!((y, m, d), (2020, 10, 1));
+    }
+}
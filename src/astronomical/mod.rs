@@ -7,7 +7,10 @@ pub mod core;
 pub mod lunar;
 pub mod solar;
 
+pub use self::core::{AstronomicalConfig, Ayanamsha};
 pub use calendar::BsCalendar as AstronomicalCalendar;
 pub use calendar::BsDate;
+pub use calendar::month_start_dates;
+pub use lunar::panchanga::Panchanga;
 pub use lunar::tithi::TithiCalculator;
 pub use solar::sankranti::SankrantiFinder;
@@ -1,7 +1,7 @@
 //! Sun position calculations
 //! Uses simplified VSOP87 or full precision depending on features
 
-use crate::astronomical::core::{JulianDay, constants::*};
+use crate::astronomical::core::{JulianDay, ZodiacSign, constants::*, time::get_ayanamsha};
 
 pub struct SolarCalculator;
 
@@ -56,6 +56,66 @@ impl SolarCalculator {
 
         normalize_degrees(true_long + nutation)
     }
+
+    /// Calculate the mean obliquity of the ecliptic in degrees (Meeus 22.2)
+    pub fn mean_obliquity(jd: JulianDay) -> f64 {
+        let t = jd.centuries_since_j2000();
+        OBLIQUITY_J2000 - (46.8150 * t + 0.00059 * t * t - 0.001813 * t * t * t) / 3600.0
+    }
+
+    /// Calculate the Sun's apparent right ascension in degrees (0-360)
+    pub fn right_ascension(jd: JulianDay) -> f64 {
+        let epsilon = Self::apparent_obliquity(jd) * DEG_TO_RAD;
+        let lambda = Self::apparent_longitude(jd) * DEG_TO_RAD;
+
+        normalize_degrees((epsilon.cos() * lambda.sin()).atan2(lambda.cos()) * RAD_TO_DEG)
+    }
+
+    /// Calculate the Sun's apparent declination in degrees
+    pub fn declination(jd: JulianDay) -> f64 {
+        let epsilon = Self::apparent_obliquity(jd) * DEG_TO_RAD;
+        let lambda = Self::apparent_longitude(jd) * DEG_TO_RAD;
+
+        (epsilon.sin() * lambda.sin()).asin() * RAD_TO_DEG
+    }
+
+    /// Mean obliquity corrected for nutation (used with apparent positions)
+    fn apparent_obliquity(jd: JulianDay) -> f64 {
+        let t = jd.centuries_since_j2000();
+        let omega = 125.04 - 1934.136 * t;
+        Self::mean_obliquity(jd) + 0.00256 * (omega * DEG_TO_RAD).cos()
+    }
+
+    /// Calculate the equation of time in minutes (apparent minus mean solar time)
+    ///
+    /// Positive values mean a sundial runs ahead of the clock, e.g. about
+    /// +16 minutes in early November and -14 minutes in mid February.
+    pub fn equation_of_time(jd: JulianDay) -> f64 {
+        let t = jd.centuries_since_j2000();
+        let omega = (125.04 - 1934.136 * t) * DEG_TO_RAD;
+        let epsilon = Self::apparent_obliquity(jd) * DEG_TO_RAD;
+        let alpha = Self::right_ascension(jd);
+        let nutation_in_longitude = -0.00478 * omega.sin();
+
+        // Meeus (28.1); 0.0057183° is the aberration of the mean Sun
+        let e =
+            Self::mean_longitude(jd) - 0.0057183 - alpha + nutation_in_longitude * epsilon.cos();
+
+        // Degrees of hour angle to minutes of time
+        ((e + 180.0).rem_euclid(360.0) - 180.0) * 4.0
+    }
+
+    /// Calculate local apparent (solar) noon at Kathmandu
+    ///
+    /// Returns the instant, in UTC, when the Sun crosses the Kathmandu
+    /// meridian on the NPT civil day containing `jd`.
+    pub fn solar_noon(jd: JulianDay) -> JulianDay {
+        // Local mean noon at the Kathmandu meridian, in hours NPT (~12:04)
+        let mean_noon_npt = 12.0 + NEPAL_TZ_OFFSET - NEPAL_LONGITUDE / 15.0;
+        let approx = jd.start_of_day_npt().add_days(mean_noon_npt / 24.0);
+
+        approx.add_days(-Self::equation_of_time(approx) / 1440.0)
+    }
 }
 
 /// Normalize angle to 0-360 degrees
@@ -63,10 +123,38 @@ fn normalize_degrees(angle: f64) -> f64 {
     angle.rem_euclid(360.0)
 }
 
+/// Calculate the Sun's rashi (sign of its nirayana longitude, Lahiri ayanamsha)
+///
+/// Uses the same VSOP87 longitude as [`super::sankranti::SankrantiFinder`],
+/// so the rashi changes exactly at each Sankranti.
+pub fn sun_rashi(jd: JulianDay) -> ZodiacSign {
+    let sayana = super::vsop87::Vsop87Calculator::sun_apparent_longitude(jd);
+    ZodiacSign::from_longitude(sayana - get_ayanamsha(jd))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_sun_rashi_changes_at_sankranti() {
+        use crate::astronomical::solar::sankranti::SankrantiFinder;
+
+        let approx = JulianDay::from_gregorian(2024, 4, 1, 0.0);
+        let mesh = SankrantiFinder::find_sankranti(0, approx)
+            .unwrap()
+            .julian_day;
+        let minute = 1.0 / 1440.0;
+        assert_eq!(sun_rashi(mesh.add_days(-minute)), ZodiacSign::Pisces);
+        assert_eq!(sun_rashi(mesh.add_days(minute)), ZodiacSign::Aries);
+
+        let makara = SankrantiFinder::find_sankranti(9, mesh.add_days(270.0))
+            .unwrap()
+            .julian_day;
+        assert_eq!(sun_rashi(makara.add_days(-minute)), ZodiacSign::Sagittarius);
+        assert_eq!(sun_rashi(makara.add_days(minute)), ZodiacSign::Capricorn);
+    }
+
     #[test]
     fn test_sun_longitude_j2000() {
         let jd = JulianDay(J2000_0);
@@ -75,4 +163,64 @@ mod tests {
         // Sun should be near 280° at J2000.0
         assert!((longitude - 280.0).abs() < 5.0);
     }
+
+    #[test]
+    fn test_mean_obliquity() {
+        assert!(
+            (SolarCalculator::mean_obliquity(JulianDay(J2000_0)) - OBLIQUITY_J2000).abs() < 1e-9
+        );
+
+        // Meeus example 22.a: 1987 April 10.0 TD, ε0 = 23°26'27.407"
+        let eps = SolarCalculator::mean_obliquity(JulianDay(2446895.5));
+        assert!((eps - 23.440946).abs() < 1e-5, "ε0 = {}", eps);
+    }
+
+    #[test]
+    fn test_declination_at_solstices() {
+        let june = SolarCalculator::declination(JulianDay::from_gregorian(2024, 6, 20, 20.85));
+        let december = SolarCalculator::declination(JulianDay::from_gregorian(2024, 12, 21, 9.33));
+        assert!((june - 23.44).abs() < 0.01, "δ = {}", june);
+        assert!((december + 23.44).abs() < 0.01, "δ = {}", december);
+
+        // Right ascension is 6h and 18h at the solstices
+        let ra = SolarCalculator::right_ascension(JulianDay::from_gregorian(2024, 6, 20, 20.85));
+        assert!((ra - 90.0).abs() < 0.1, "α = {}", ra);
+    }
+
+    #[test]
+    fn test_equation_of_time_meeus_28b() {
+        // Meeus example 28.b: 1992 October 13.0, E = +13m 42.6s
+        let eot = SolarCalculator::equation_of_time(JulianDay(2448908.5));
+        assert!((eot - 13.71).abs() < 0.2, "EoT = {}", eot);
+    }
+
+    #[test]
+    fn test_equation_of_time_annual_swing() {
+        // Maximum near 3 November, minimum near 11 February
+        let november =
+            SolarCalculator::equation_of_time(JulianDay::from_gregorian(2024, 11, 3, 0.0));
+        let february =
+            SolarCalculator::equation_of_time(JulianDay::from_gregorian(2024, 2, 11, 0.0));
+        assert!((november - 16.4).abs() < 0.3, "EoT = {}", november);
+        assert!((february + 14.2).abs() < 0.3, "EoT = {}", february);
+
+        let (min, max) = (0..366)
+            .map(|d| {
+                SolarCalculator::equation_of_time(
+                    JulianDay::from_gregorian(2024, 1, 1, 0.0).add_days(d as f64),
+                )
+            })
+            .fold((f64::MAX, f64::MIN), |(lo, hi), e| (lo.min(e), hi.max(e)));
+        assert!((-15.0..-14.0).contains(&min), "min = {}", min);
+        assert!((16.0..17.0).contains(&max), "max = {}", max);
+    }
+
+    #[test]
+    fn test_solar_noon_kathmandu() {
+        // Around 3 November the Sun transits about 16 minutes before mean noon (~12:04 NPT)
+        let noon = SolarCalculator::solar_noon(JulianDay::from_gregorian(2024, 11, 3, 6.0));
+        let (y, m, d, hour) = crate::astronomical::core::time::utc_to_npt(noon).to_gregorian();
+        assert_eq!((y, m, d), (2024, 11, 3));
+        assert!((hour - 11.79).abs() < 0.02, "solar noon at {} NPT", hour);
+    }
 }
@@ -6,8 +6,14 @@
 use super::vsop87::Vsop87Calculator;
 use crate::astronomical::calendar::BsDate;
 use crate::astronomical::core::{
-    JulianDay, newton_raphson::NewtonRaphsonSolver, time::get_ayanamsha,
+    AstronomicalConfig, JulianDay, ZodiacSign,
+    constants::{SUN_MEAN_DAILY_MOTION, ZODIAC_DEGREES},
+    newton_raphson::NewtonRaphsonSolver,
+    time::utc_to_npt,
 };
+use crate::core::festival::fixed_festival_names;
+use std::cmp::Ordering;
+use std::fmt;
 
 /// Information about a Sankranti event
 #[derive(Debug, Clone, Copy)]
@@ -38,7 +44,28 @@ impl Sankranti {
         }
     }
 
-    /// Convert to BS date
+    /// Festival observed on this Sankranti, e.g. "Maghe Sankranti" for Makara
+    ///
+    /// Each Sankranti starts a solar month, so this is the fixed observance
+    /// on day 1 of that month, if any.
+    pub fn festival_name(&self) -> Option<&'static str> {
+        fixed_festival_names(self.zodiac_sign + 1, 1).map(|(name, _)| name)
+    }
+
+    /// Devanagari form of [`Sankranti::festival_name`], e.g. "माघे संक्रान्ति"
+    pub fn festival_name_unicode(&self) -> Option<&'static str> {
+        fixed_festival_names(self.zodiac_sign + 1, 1).map(|(_, name_unicode)| name_unicode)
+    }
+
+    /// Gregorian date and fractional hour of the transit in Nepal Standard Time
+    ///
+    /// The civil date a Sankranti is observed on is the NPT one; a transit
+    /// late in the UTC evening already falls on the next day in Nepal.
+    pub fn local_datetime_npt(&self) -> (i32, u8, u8, f64) {
+        utc_to_npt(self.julian_day).to_gregorian()
+    }
+
+    /// Convert to BS date (of the NPT civil day containing the transit)
     pub fn to_bs_date(&self) -> BsDate {
         BsDate::from_julian_day(self.julian_day).unwrap_or(BsDate {
             year: 0,
@@ -48,6 +75,41 @@ impl Sankranti {
     }
 }
 
+impl fmt::Display for Sankranti {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        // Date of the transit in Nepal, matching the civil day it is observed on
+        let (y, m, d, _) = self.local_datetime_npt();
+        f.pad(&format!(
+            "{} Sankranti ({}-{:02}-{:02})",
+            self.sign_name(),
+            y,
+            m,
+            d
+        ))
+    }
+}
+
+/// Sankrantis order by the moment of transit
+impl PartialEq for Sankranti {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for Sankranti {}
+
+impl PartialOrd for Sankranti {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Sankranti {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.julian_day.0.total_cmp(&other.julian_day.0)
+    }
+}
+
 pub struct SankrantiFinder;
 
 impl SankrantiFinder {
@@ -57,28 +119,35 @@ impl SankrantiFinder {
     /// * `target_sign` - Zodiac sign index (0-11)
     /// * `approx_jd` - Approximate Julian Day to start searching from
     pub fn find_sankranti(target_sign: u8, approx_jd: JulianDay) -> Result<Sankranti, String> {
-        let target_long = (target_sign as f64) * 30.0;
-
-        // Function to find root for: nirayana_sun_longitude(jd) - target_long = 0
-        let f = |jd: f64| {
-            let julian_day = JulianDay(jd);
-            let sayana_long = Vsop87Calculator::sun_apparent_longitude(julian_day);
-            let ayanamsha = get_ayanamsha(julian_day);
-            let nirayana_long = (sayana_long - ayanamsha).rem_euclid(360.0);
+        Self::find_sankranti_with_config(target_sign, approx_jd, &AstronomicalConfig::default())
+    }
 
-            // println!("JD: {}, Sayana: {}, Ay: {}, Nirayana: {}", jd, sayana_long, ayanamsha, nirayana_long);
+    /// Find when the Sun enters a specific zodiac sign using the configured ayanamsha
+    ///
+    /// Returns the transit nearest to `approx_jd`. The seed only has to be
+    /// within about half a year: it is first moved by the remaining
+    /// longitude at the Sun's mean motion, so the solver starts within hours
+    /// of the transit.
+    pub fn find_sankranti_with_config(
+        target_sign: u8,
+        approx_jd: JulianDay,
+        config: &AstronomicalConfig,
+    ) -> Result<Sankranti, String> {
+        let target_long = (target_sign as f64) * 30.0;
 
-            let mut diff = nirayana_long - target_long;
+        // Root of: nirayana_sun_longitude(jd) - target_long = 0
+        let f = |jd: f64| Self::longitude_offset(JulianDay(jd), target_long, config);
 
-            // Normalize difference to [-180, 180] for root finding
-            diff = (diff + 180.0).rem_euclid(360.0) - 180.0;
-            diff
-        };
+        // Two mean-motion steps absorb the Sun's +-3% speed variation
+        let mut guess = approx_jd.0;
+        for _ in 0..2 {
+            guess -= f(guess) / SUN_MEAN_DAILY_MOTION;
+        }
 
         let solver = NewtonRaphsonSolver::new(50, 1e-8);
 
         // Use numerical derivative for simplicity (h = 0.001 days is about 1.4 minutes)
-        match solver.solve_numerical(f, approx_jd.0, 0.0001) {
+        match solver.solve_numerical(f, guess, 0.0001) {
             Ok(root_jd) => Ok(Sankranti {
                 zodiac_sign: target_sign,
                 julian_day: JulianDay(root_jd),
@@ -89,6 +158,21 @@ impl SankrantiFinder {
 
     /// Find all Sankrantis in a given BS year
     pub fn find_all_in_year(bs_year: i32) -> Result<Vec<Sankranti>, String> {
+        Self::find_all_in_year_with_config(bs_year, &AstronomicalConfig::default())
+    }
+
+    /// Nirayana solar longitude minus `target_long`, normalized to [-180, 180)
+    fn longitude_offset(jd: JulianDay, target_long: f64, config: &AstronomicalConfig) -> f64 {
+        let sayana_long = Vsop87Calculator::sun_apparent_longitude(jd);
+        let nirayana_long = (sayana_long - config.ayanamsha.value(jd)).rem_euclid(360.0);
+        (nirayana_long - target_long + 180.0).rem_euclid(360.0) - 180.0
+    }
+
+    /// Find all Sankrantis in a given BS year using the configured ayanamsha
+    pub fn find_all_in_year_with_config(
+        bs_year: i32,
+        config: &AstronomicalConfig,
+    ) -> Result<Vec<Sankranti>, String> {
         let mut results = Vec::new();
 
         // Mesh Sankranti 2081 is around April 13, 2024
@@ -96,13 +180,166 @@ impl SankrantiFinder {
         let approx_greg_year = bs_year - 57;
         let mut current_search_jd = JulianDay::from_gregorian(approx_greg_year, 4, 1, 0.0);
 
-        for sign in 0..12 {
-            let sankranti = Self::find_sankranti(sign as u8, current_search_jd)?;
+        let mut sign = ZodiacSign::Aries;
+        for _ in 0..12 {
+            let sankranti =
+                Self::find_sankranti_with_config(sign as u8, current_search_jd, config)?;
             results.push(sankranti);
-            // Move search point forward by ~30 days for next sign
-            current_search_jd = JulianDay(sankranti.julian_day.0 + 25.0);
+            // Move search point forward by the mean time the Sun spends in a sign
+            current_search_jd = sankranti.julian_day + ZODIAC_DEGREES / SUN_MEAN_DAILY_MOTION;
+            sign = sign.next();
         }
 
         Ok(results)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::astronomical::core::Ayanamsha;
+
+    #[test]
+    fn test_poor_seed_converges_to_nearest_transit() {
+        // Mesh Sankranti 2081 falls on 2024-04-13 (NPT)
+        let expected = SankrantiFinder::find_sankranti(
+            ZodiacSign::Aries as u8,
+            JulianDay::from_gregorian(2024, 4, 12, 0.0),
+        )
+        .unwrap();
+
+        for (month, day) in [(1, 20), (3, 1), (5, 25), (8, 30), (4, 13)] {
+            let seed = JulianDay::from_gregorian(2024, month, day, 0.0);
+            let found = SankrantiFinder::find_sankranti(ZodiacSign::Aries as u8, seed).unwrap();
+            assert_eq!(found.zodiac_sign, 0);
+            assert!(
+                (found.julian_day.0 - expected.julian_day.0).abs() < 1e-4,
+                "seed {}-{}",
+                month,
+                day
+            );
+        }
+
+        // Near the 360 degree wrap: Meena ends where Mesh begins
+        let seed = JulianDay::from_gregorian(2024, 2, 1, 0.0);
+        let meena = SankrantiFinder::find_sankranti(ZodiacSign::Pisces as u8, seed).unwrap();
+        let (y, m, d, _) = meena.local_datetime_npt();
+        assert_eq!((y, m, d), (2024, 3, 14));
+    }
+
+    #[test]
+    fn test_sort_by_transit_time() {
+        let mut sankrantis = SankrantiFinder::find_all_in_year(2081).unwrap();
+        let expected = sankrantis.clone();
+        sankrantis.reverse();
+        sankrantis.swap(2, 7);
+
+        sankrantis.sort();
+        assert_eq!(sankrantis, expected);
+        let signs: Vec<u8> = sankrantis.iter().map(|s| s.zodiac_sign).collect();
+        assert_eq!(signs, (0..12).collect::<Vec<u8>>());
+        assert!(sankrantis[0] < sankrantis[1]);
+    }
+
+    #[test]
+    fn test_local_datetime_npt_late_evening_transit() {
+        let sankranti = Sankranti {
+            zodiac_sign: 0,
+            julian_day: JulianDay::from_gregorian_hms(2024, 4, 12, 20, 30, 0),
+        };
+
+        let (uy, um, ud, _) = sankranti.julian_day.to_gregorian();
+        assert_eq!((uy, um, ud), (2024, 4, 12));
+
+        let (y, m, d, hour) = sankranti.local_datetime_npt();
+        assert_eq!((y, m, d), (2024, 4, 13));
+        assert!((hour - 2.25).abs() < 1e-6);
+        assert_eq!(sankranti.to_bs_date().to_gregorian().unwrap(), (y, m, d));
+    }
+
+    #[test]
+    fn test_delta_t_moves_midnight_transit() {
+        // Vrishabha Sankranti 2000 BS lands ~20 s before NPT midnight only
+        // once delta-T (~24 s in 1943) is applied; the published calendar
+        // starts Jestha on 1943-05-15 and gives Baisakh 30 and Jestha 32 days
+        let sankrantis = SankrantiFinder::find_all_in_year(2000).unwrap();
+        let vrishabha = &sankrantis[1];
+        let (y, m, d, hour) = vrishabha.local_datetime_npt();
+        assert_eq!((y, m, d), (1943, 5, 14));
+        assert!(hour > 23.99);
+
+        let lengths =
+            crate::astronomical::calendar::SolarMonthCalculator::calculate_month_lengths(2000)
+                .unwrap();
+        assert_eq!(&lengths[..2], &[30, 32]);
+    }
+
+    #[test]
+    fn test_festival_names() {
+        let at = |zodiac_sign| Sankranti {
+            zodiac_sign,
+            julian_day: JulianDay::from_gregorian(2025, 1, 14, 0.0),
+        };
+
+        assert_eq!(at(9).festival_name(), Some("Maghe Sankranti"));
+        assert_eq!(at(9).festival_name_unicode(), Some("माघे संक्रान्ति"));
+        assert_eq!(at(3).festival_name(), Some("Saune Sankranti"));
+        assert_eq!(at(5).festival_name(), None);
+        assert_eq!(at(5).festival_name_unicode(), None);
+    }
+
+    #[test]
+    fn test_sankranti_display() {
+        // 2024-04-12 20:00 UTC is already 2024-04-13 in Nepal
+        let sankranti = Sankranti {
+            zodiac_sign: 0,
+            julian_day: JulianDay::from_gregorian(2024, 4, 12, 20.0),
+        };
+        assert_eq!(sankranti.to_string(), "Mesh Sankranti (2024-04-13)");
+    }
+
+    #[test]
+    fn test_default_config_is_lahiri() {
+        let approx = JulianDay::from_gregorian(2024, 4, 1, 0.0);
+        let default = SankrantiFinder::find_sankranti(0, approx).unwrap();
+        let lahiri = SankrantiFinder::find_sankranti_with_config(
+            0,
+            approx,
+            &AstronomicalConfig::with_ayanamsha(Ayanamsha::Lahiri),
+        )
+        .unwrap();
+        assert_eq!(default.julian_day, lahiri.julian_day);
+    }
+
+    #[test]
+    fn test_ayanamsha_shifts_sankranti() {
+        let approx = JulianDay::from_gregorian(2024, 4, 1, 0.0);
+        let lahiri = SankrantiFinder::find_sankranti(0, approx).unwrap();
+
+        for ayanamsha in [
+            Ayanamsha::Raman,
+            Ayanamsha::KrishnamurtiKP,
+            Ayanamsha::FaganBradley,
+        ] {
+            let other = SankrantiFinder::find_sankranti_with_config(
+                0,
+                approx,
+                &AstronomicalConfig::with_ayanamsha(ayanamsha),
+            )
+            .unwrap();
+
+            // A larger ayanamsha delays the transit by offset / solar motion (~0.98°/day in April)
+            let offset =
+                ayanamsha.value(lahiri.julian_day) - Ayanamsha::Lahiri.value(lahiri.julian_day);
+            let expected = offset / 0.983;
+            let shift = other.julian_day.0 - lahiri.julian_day.0;
+            assert!(
+                (shift - expected).abs() < 0.02,
+                "{:?}: shift {} vs expected {}",
+                ayanamsha,
+                shift,
+                expected
+            );
+        }
+    }
+}
@@ -5,6 +5,9 @@
 //!
 //! This implementation uses the most significant terms from VSOP87D
 //! (heliocentric spherical coordinates) providing ~0.01° accuracy.
+//!
+//! The theory is defined in Terrestrial Time (TT); only the apparent
+//! longitude accepts UTC and applies delta-T itself.
 
 use crate::astronomical::core::{JulianDay, constants::*};
 
@@ -53,7 +56,7 @@ const L0_TERMS: &[VsopTerm] = &[
 
 /// L1 terms for Earth's heliocentric longitude
 const L1_TERMS: &[VsopTerm] = &[
-    VsopTerm::new(6283.07584999140, 0.0, 0.0),
+    VsopTerm::new(6283.31966747491, 0.0, 0.0),
     VsopTerm::new(0.00206058863, 2.67823455584, 6283.07584999140),
     VsopTerm::new(0.00004303419, 2.63512650414, 12566.1516999828),
     VsopTerm::new(0.00000425264, 1.59046982018, 3.52311834900),
@@ -201,8 +204,11 @@ impl Vsop87Calculator {
 
     /// Calculate Sun's apparent longitude (includes nutation and aberration)
     ///
-    /// This is the most accurate representation of Sun's position
+    /// This is the most accurate representation of Sun's position. Unlike
+    /// the geometric methods above, which take Terrestrial Time, `jd` here is
+    /// UTC and is converted with [`JulianDay::to_tt`].
     pub fn sun_apparent_longitude(jd: JulianDay) -> f64 {
+        let jd = jd.to_tt();
         let true_lon = Self::sun_true_longitude(jd);
         let nutation_and_aberration = Self::nutation_longitude(jd);
 
@@ -262,6 +268,14 @@ mod tests {
         assert!(lat.abs() < 0.001, "Earth latitude = {}°", lat);
     }
 
+    #[test]
+    fn test_sun_apparent_longitude_meeus_25a() {
+        // Meeus, Example 25.a: 1992 October 13.0 TD, apparent longitude 199.90988°
+        let jd = JulianDay(2448908.5);
+        let lon = Vsop87Calculator::sun_apparent_longitude(jd);
+        assert!((lon - 199.90988).abs() < 0.01, "Sun longitude = {}°", lon);
+    }
+
     #[test]
     fn test_vernal_equinox_2020() {
         // March 20, 2020, 03:50 UTC (vernal equinox)
@@ -0,0 +1,101 @@
+//! Incremental construction of Nepali dates
+
+use crate::core::date::NepaliDate;
+use crate::core::error::{NpdatetimeError, Result};
+
+/// Builder for a [`NepaliDate`] whose fields are set one at a time
+///
+/// Month and day default to 1 when unset; the year is required. Nothing is
+/// validated until [`NepaliDateBuilder::build`].
+///
+/// # Examples:
+/// ```
+/// # use npdatetime::NepaliDate;
+/// # if cfg!(any(feature = "lookup-tables", feature = "astronomical")) {
+/// let date = NepaliDate::builder().year(2077).month(5).build().unwrap();
+/// assert_eq!(date, NepaliDate::new(2077, 5, 1).unwrap());
+/// # }
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct NepaliDateBuilder {
+    year: Option<i32>,
+    month: Option<u8>,
+    day: Option<u8>,
+}
+
+impl NepaliDateBuilder {
+    /// Creates a builder with no fields set
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the BS year
+    pub fn year(mut self, year: i32) -> Self {
+        self.year = Some(year);
+        self
+    }
+
+    /// Sets the month (1-12)
+    pub fn month(mut self, month: u8) -> Self {
+        self.month = Some(month);
+        self
+    }
+
+    /// Sets the day of the month
+    pub fn day(mut self, day: u8) -> Self {
+        self.day = Some(day);
+        self
+    }
+
+    /// Validates the fields and creates the date
+    pub fn build(&self) -> Result<NepaliDate> {
+        let year = self
+            .year
+            .ok_or_else(|| NpdatetimeError::InvalidDate("Year must be set".to_string()))?;
+        NepaliDate::new(year, self.month.unwrap_or(1), self.day.unwrap_or(1))
+    }
+}
+
+impl NepaliDate {
+    /// Returns a [`NepaliDateBuilder`] for setting the fields incrementally
+    pub fn builder() -> NepaliDateBuilder {
+        NepaliDateBuilder::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[cfg(any(feature = "lookup-tables", feature = "astronomical"))]
+    #[test]
+    fn test_full_and_partial_build() {
+        let date = NepaliDate::builder()
+            .day(19)
+            .month(5)
+            .year(2077)
+            .build()
+            .unwrap();
+        assert_eq!(date, NepaliDate::new(2077, 5, 19).unwrap());
+
+        let partial = NepaliDate::builder().year(2077).month(5).build().unwrap();
+        assert_eq!(partial, NepaliDate::new(2077, 5, 1).unwrap());
+        assert_eq!(
+            NepaliDate::builder().year(2081).build().unwrap(),
+            NepaliDate::new(2081, 1, 1).unwrap()
+        );
+
+        assert!(matches!(
+            NepaliDate::builder().year(2077).month(5).day(40).build(),
+            Err(NpdatetimeError::InvalidDate(_))
+        ));
+    }
+
+    #[test]
+    fn test_year_required() {
+        assert!(matches!(
+            NepaliDate::builder().month(5).day(19).build(),
+            Err(NpdatetimeError::InvalidDate(_))
+        ));
+    }
+}
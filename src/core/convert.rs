@@ -0,0 +1,135 @@
+//! Numeric BS ↔ AD conversion for hot loops
+//!
+//! These work on plain `(year, month, day)` tuples and skip the component
+//! validation of [`NepaliDate::new`] and [`validate_gregorian`]. The caller
+//! guarantees the input is a real date; out-of-range components give a
+//! meaningless result rather than an error. Years outside the calendar
+//! source still fail. [`NepaliDate::to_gregorian`] and
+//! [`NepaliDate::from_gregorian`] are the validating forms.
+//!
+//! [`validate_gregorian`]: crate::core::date::validate_gregorian
+
+use crate::core::date::{
+    BS_EPOCH_AD, BS_EPOCH_YEAR, NepaliDate, days_to_gregorian, gregorian_to_days,
+};
+use crate::core::error::{NpdatetimeError, Result};
+
+/// Converts a BS date to Gregorian (AD)
+///
+/// # Examples:
+/// ```
+/// # if cfg!(any(feature = "lookup-tables", feature = "astronomical")) {
+/// use npdatetime::core::convert::{ad_to_bs, bs_to_ad};
+///
+/// assert_eq!(bs_to_ad(2077, 5, 19).unwrap(), (2020, 9, 4));
+/// assert_eq!(ad_to_bs(2020, 9, 4).unwrap(), (2077, 5, 19));
+/// # }
+/// ```
+pub fn bs_to_ad(year: i32, month: u8, day: u8) -> Result<(i32, u8, u8)> {
+    let mut total_days = day as i64 - 1;
+    for y in year..BS_EPOCH_YEAR {
+        total_days -= NepaliDate::days_in_year(y)? as i64;
+    }
+    for y in BS_EPOCH_YEAR..year {
+        total_days += NepaliDate::days_in_year(y)? as i64;
+    }
+    for m in 1..month {
+        total_days += NepaliDate::days_in_month(year, m)? as i64;
+    }
+
+    Ok(days_to_gregorian(epoch_days() + total_days))
+}
+
+/// Converts a Gregorian (AD) date to BS
+pub fn ad_to_bs(year: i32, month: u8, day: u8) -> Result<(i32, u8, u8)> {
+    let mut remaining_days = gregorian_to_days(year, month, day) - epoch_days();
+    if remaining_days < 0 {
+        return Err(NpdatetimeError::BeforeEpoch(
+            "Date is before the BS epoch".to_string(),
+        ));
+    }
+
+    let mut bs_year = BS_EPOCH_YEAR;
+    loop {
+        let year_days = NepaliDate::days_in_year(bs_year)? as i64;
+        if remaining_days < year_days {
+            break;
+        }
+        remaining_days -= year_days;
+        bs_year += 1;
+    }
+
+    let mut bs_month = 1u8;
+    loop {
+        let month_days = NepaliDate::days_in_month(bs_year, bs_month)? as i64;
+        if remaining_days < month_days {
+            break;
+        }
+        remaining_days -= month_days;
+        bs_month += 1;
+    }
+
+    Ok((bs_year, bs_month, (remaining_days + 1) as u8))
+}
+
+/// Day number (as in `gregorian_to_days`) of 1975-01-01 BS
+fn epoch_days() -> i64 {
+    let (year, month, day) = BS_EPOCH_AD;
+    gregorian_to_days(year, month, day)
+}
+
+#[cfg(test)]
+#[cfg(any(feature = "lookup-tables", feature = "astronomical"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_agrees_with_methods() {
+        let mut ordinal = 1;
+        while ordinal <= NepaliDate::MAX.to_ordinal() {
+            let date = NepaliDate::from_ordinal(ordinal).unwrap();
+            let ad = date.to_gregorian().unwrap();
+            assert_eq!(bs_to_ad(date.year, date.month, date.day).unwrap(), ad);
+
+            let (y, m, d) = ad;
+            let bs = NepaliDate::from_gregorian(y, m, d).unwrap();
+            assert_eq!(ad_to_bs(y, m, d).unwrap(), (bs.year, bs.month, bs.day));
+            ordinal += 13;
+        }
+
+        let (y, m, d) = NepaliDate::MAX.to_gregorian().unwrap();
+        assert_eq!(ad_to_bs(y, m, d).unwrap(), (2100, 12, 30));
+        assert!(matches!(
+            ad_to_bs(1918, 4, 12),
+            Err(NpdatetimeError::BeforeEpoch(_))
+        ));
+    }
+
+    #[test]
+    fn test_bs_to_ad_before_epoch() {
+        let (y, m, d) = BS_EPOCH_AD;
+        let epoch = gregorian_to_days(y, m, d);
+
+        for ordinal in [0, -1, -400] {
+            match NepaliDate::from_ordinal(ordinal) {
+                Ok(date) => {
+                    let ad = bs_to_ad(date.year, date.month, date.day).unwrap();
+                    assert_eq!(ad, days_to_gregorian(epoch + ordinal as i64 - 1));
+                    assert_eq!(date.to_gregorian().unwrap(), ad);
+                    assert_eq!(
+                        date.days_between(&NepaliDate::MIN).unwrap(),
+                        1 - ordinal as i64
+                    );
+                    assert_eq!(NepaliDate::from_ordinal(date.to_ordinal()).unwrap(), date);
+                }
+                // Only the astronomical provider covers years before 1975 BS
+                Err(e) => assert!(matches!(e, NpdatetimeError::BeforeEpoch(_))),
+            }
+        }
+
+        if cfg!(feature = "astronomical") {
+            let last_of_1974 = NepaliDate::from_ordinal(0).unwrap();
+            assert_eq!(last_of_1974.to_gregorian().unwrap(), (1918, 4, 12));
+        }
+    }
+}
@@ -1,10 +1,16 @@
+use crate::core::convert;
 use crate::core::error::{NpdatetimeError, Result};
+use crate::core::format::to_devanagari_digits;
+use std::cmp::Ordering;
 use std::fmt;
 
 // Reference point: Start of BS 1975
 pub const BS_EPOCH_YEAR: i32 = 1975;
 pub const BS_EPOCH_AD: (i32, u8, u8) = (1918, 4, 13);
 
+/// Nepal Standard Time offset from UTC in seconds (UTC+5:45)
+pub const NPT_OFFSET_SECONDS: i32 = 20_700;
+
 /// Month names in Nepali
 pub const NEPALI_MONTHS: [&str; 12] = [
     "Baisakh", "Jestha", "Ashadh", "Shrawan", "Bhadra", "Ashwin", "Kartik", "Mangsir", "Poush",
@@ -50,6 +56,31 @@ pub struct NepaliDate {
 }
 
 impl NepaliDate {
+    /// Earliest supported date (1975-01-01 BS, 1918-04-13 AD)
+    pub const MIN: NepaliDate = NepaliDate::from_ymd_unchecked(BS_EPOCH_YEAR, 1, 1);
+
+    /// Last date covered by the embedded lookup tables (2100-12-30 BS)
+    pub const MAX: NepaliDate = NepaliDate::from_ymd_unchecked(2100, 12, 30);
+
+    /// Creates a date without validating it, usable in `const` and `static` items
+    ///
+    /// The caller is responsible for passing a real date; use
+    /// [`NepaliDate::new`] otherwise. Debug builds only check that the month is
+    /// 1-12 and the day 1-32, since month lengths are not available in a
+    /// `const` context.
+    ///
+    /// # Examples:
+    /// ```
+    /// # use npdatetime::NepaliDate;
+    /// const NEW_YEAR_2081: NepaliDate = NepaliDate::from_ymd_unchecked(2081, 1, 1);
+    /// assert_eq!(NEW_YEAR_2081.to_string(), "2081-01-01");
+    /// ```
+    pub const fn from_ymd_unchecked(year: i32, month: u8, day: u8) -> Self {
+        debug_assert!(month >= 1 && month <= 12, "month must be 1-12");
+        debug_assert!(day >= 1 && day <= 32, "day must be 1-32");
+        NepaliDate { year, month, day }
+    }
+
     /// Creates a new Nepali date
     pub fn new(year: i32, month: u8, day: u8) -> Result<Self> {
         if !(1..=12).contains(&month) {
@@ -70,6 +101,76 @@ impl NepaliDate {
         Ok(NepaliDate { year, month, day })
     }
 
+    /// Creates a Nepali date, clamping out-of-range components instead of failing
+    ///
+    /// The month is clamped to 1-12 and the day to the length of that month,
+    /// so e.g. day 35 becomes the month's last day. Only a year outside the
+    /// supported range is an error.
+    pub fn new_clamped(year: i32, month: u8, day: u8) -> Result<Self> {
+        let month = month.clamp(1, 12);
+        let day = day.clamp(1, Self::days_in_month(year, month)?);
+        Ok(NepaliDate { year, month, day })
+    }
+
+    /// Creates a Nepali date, rolling out-of-range components over
+    ///
+    /// Months past 12 carry into the following years, then days past the end
+    /// of the month carry into the following months using their real
+    /// lengths, so Baisakh 45 becomes a date in Jestha. Month 0 and day 0
+    /// roll back to the last month or day before. Fails only when the
+    /// result leaves the supported range.
+    ///
+    /// # Examples:
+    /// ```
+    /// # use npdatetime::NepaliDate;
+    /// # if cfg!(any(feature = "lookup-tables", feature = "astronomical")) {
+    /// // Baisakh 2081 has 31 days
+    /// let date = NepaliDate::normalize(2081, 1, 45).unwrap();
+    /// assert_eq!(date.to_string(), "2081-02-14");
+    /// # }
+    /// ```
+    pub fn normalize(year: i32, month: u8, day: u8) -> Result<Self> {
+        // A failed astronomical search reports a month of 0 days, which
+        // would otherwise carry days forward forever
+        let nonzero_days_in_month = |year: i32, month: u8| match Self::days_in_month(year, month)? {
+            0 => Err(NpdatetimeError::InvalidDate(format!(
+                "Month {}-{:02} has no days",
+                year, month
+            ))),
+            days => Ok(days),
+        };
+
+        let months = month as i32 - 1;
+        let mut year = year + months.div_euclid(12);
+        let mut month = months.rem_euclid(12) as u8 + 1;
+
+        if day == 0 {
+            if month == 1 {
+                year -= 1;
+                month = 12;
+            } else {
+                month -= 1;
+            }
+            let day = nonzero_days_in_month(year, month)?;
+            return Ok(NepaliDate { year, month, day });
+        }
+
+        let mut day = day;
+        loop {
+            let month_days = nonzero_days_in_month(year, month)?;
+            if day <= month_days {
+                return Ok(NepaliDate { year, month, day });
+            }
+            day -= month_days;
+            if month == 12 {
+                year += 1;
+                month = 1;
+            } else {
+                month += 1;
+            }
+        }
+    }
+
     /// Returns the number of days in a given month
     pub fn days_in_month(year: i32, month: u8) -> Result<u8> {
         if !(1..=12).contains(&month) {
@@ -98,117 +199,201 @@ impl NepaliDate {
         }
 
         #[allow(unreachable_code)]
-        Err(NpdatetimeError::OutOfRange(format!(
-            "Year {} is out of supported range (or no calendar provider feature enabled)",
-            year
-        )))
+        if year < BS_EPOCH_YEAR {
+            Err(NpdatetimeError::BeforeEpoch(format!(
+                "Year {} is before the BS epoch {}",
+                year, BS_EPOCH_YEAR
+            )))
+        } else {
+            Err(NpdatetimeError::AfterSupportedRange(format!(
+                "Year {} is out of supported range (or no calendar provider feature enabled)",
+                year
+            )))
+        }
     }
 
-    /// Converts Nepali date to Gregorian date (year, month, day)
-    pub fn to_gregorian(&self) -> Result<(i32, u8, u8)> {
-        let mut total_days = 0i64;
-
-        for y in BS_EPOCH_YEAR..self.year {
-            for m in 1..=12 {
-                total_days += Self::days_in_month(y, m)? as i64;
-            }
+    /// Returns the number of days in a given year
+    pub fn days_in_year(year: i32) -> Result<u16> {
+        let mut total = 0u16;
+        for m in 1..=12 {
+            total += Self::days_in_month(year, m)? as u16;
         }
+        Ok(total)
+    }
 
+    /// Returns the day of the year (1 for Baisakh 1)
+    pub fn day_of_year(&self) -> Result<u16> {
+        let mut total = self.day as u16;
         for m in 1..self.month {
-            total_days += Self::days_in_month(self.year, m)? as i64;
+            total += Self::days_in_month(self.year, m)? as u16;
         }
+        Ok(total)
+    }
 
-        total_days += (self.day - 1) as i64;
+    /// Days left in the month after this one (0 on the last day)
+    ///
+    /// A day past the end of the month (set directly on the fields) gives
+    /// `InvalidDate` instead of underflowing.
+    pub fn days_remaining_in_month(&self) -> Result<u8> {
+        let month_days = Self::days_in_month(self.year, self.month)?;
+        month_days.checked_sub(self.day).ok_or_else(|| {
+            NpdatetimeError::InvalidDate(format!(
+                "Day must be between 1 and {}, got {}",
+                month_days, self.day
+            ))
+        })
+    }
 
-        let (mut year, mut month, mut day) = BS_EPOCH_AD;
-        let mut days_to_add = total_days;
+    /// Days left in the year after this one (0 on the last day of Chaitra)
+    pub fn days_remaining_in_year(&self) -> Result<u16> {
+        let year_days = Self::days_in_year(self.year)?;
+        let day_of_year = self.day_of_year()?;
+        year_days.checked_sub(day_of_year).ok_or_else(|| {
+            NpdatetimeError::InvalidDate(format!(
+                "Day of year must be between 1 and {}, got {}",
+                year_days, day_of_year
+            ))
+        })
+    }
 
-        while days_to_add > 0 {
-            let days_in_current_month = gregorian_days_in_month(year, month);
-            if days_to_add >= (days_in_current_month - day + 1) as i64 {
-                days_to_add -= (days_in_current_month - day + 1) as i64;
-                day = 1;
-                month += 1;
-                if month > 12 {
-                    month = 1;
-                    year += 1;
-                }
-            } else {
-                day += days_to_add as u8;
-                days_to_add = 0;
-            }
-        }
+    /// Returns true if the BS year has 366 days
+    ///
+    /// Unlike the Gregorian calendar there is no arithmetic leap rule; the
+    /// answer comes from summing the year's month lengths.
+    pub fn is_long_year(year: i32) -> Result<bool> {
+        Ok(Self::days_in_year(year)? == 366)
+    }
 
-        Ok((year, month, day))
+    /// Converts Nepali date to Gregorian date (year, month, day)
+    pub fn to_gregorian(&self) -> Result<(i32, u8, u8)> {
+        convert::bs_to_ad(self.year, self.month, self.day)
     }
 
     /// Creates a Nepali date from a Gregorian date
     pub fn from_gregorian(year: i32, month: u8, day: u8) -> Result<Self> {
-        let total_days = gregorian_days_since_epoch(year, month, day, BS_EPOCH_AD)?;
+        validate_gregorian(year, month, day)?;
+        let (bs_year, bs_month, bs_day) = convert::ad_to_bs(year, month, day)?;
+        Self::new(bs_year, bs_month, bs_day)
+    }
 
-        let mut remaining_days = total_days;
-        let mut bs_year = BS_EPOCH_YEAR;
-        let mut bs_month = 1u8;
+    /// Lazily converts a stream of Gregorian `(year, month, day)` tuples
+    ///
+    /// Each input is converted only when the iterator is advanced, so large
+    /// piped inputs are never buffered. Failed conversions are yielded as
+    /// errors without stopping the stream.
+    pub fn from_gregorian_iter<I>(iter: I) -> impl Iterator<Item = Result<NepaliDate>>
+    where
+        I: Iterator<Item = (i32, u8, u8)>,
+    {
+        iter.map(|(year, month, day)| Self::from_gregorian(year, month, day))
+    }
 
-        loop {
-            let mut year_days = 0;
-            for m in 1..=12 {
-                year_days += Self::days_in_month(bs_year, m)? as i64;
-            }
+    /// Compares the date with a Gregorian date, converting the latter to BS once
+    pub fn cmp_gregorian(&self, year: i32, month: u8, day: u8) -> Result<Ordering> {
+        let other = Self::from_gregorian(year, month, day)?;
+        Ok(self.cmp(&other))
+    }
 
-            if remaining_days >= year_days {
-                remaining_days -= year_days;
-                bs_year += 1;
-            } else {
-                break;
-            }
+    /// Returns true if this date falls strictly before `other`
+    pub fn is_before(&self, other: &NepaliDate) -> bool {
+        self < other
+    }
+
+    /// Returns true if this date falls strictly after `other`
+    pub fn is_after(&self, other: &NepaliDate) -> bool {
+        self > other
+    }
+
+    /// Returns true if both dates are the same day (same as `==`)
+    pub fn is_same_day(&self, other: &NepaliDate) -> bool {
+        self == other
+    }
+
+    /// Returns true if the dates are at most `tol` days apart, in either order
+    ///
+    /// Useful when reconciling lookup and astronomical results, which can
+    /// place a month boundary a day apart. Dates outside the supported range
+    /// are never within tolerance.
+    pub fn within_days(&self, other: &NepaliDate, tol: u8) -> bool {
+        self.days_between(other)
+            .is_ok_and(|days| days.abs() <= tol as i64)
+    }
+
+    /// Returns the next occurrence of a fixed BS month/day on or after `from`
+    ///
+    /// If `day` does not exist in a candidate year's month (e.g. Jestha 32 in
+    /// a year where Jestha has 31 days), the last day of that month is used.
+    pub fn next_anniversary(month: u8, day: u8, from: NepaliDate) -> Result<Self> {
+        if !(1..=12).contains(&month) || day == 0 {
+            return Err(NpdatetimeError::InvalidDate(format!(
+                "Invalid anniversary month/day: {}/{}",
+                month, day
+            )));
         }
 
-        while bs_month <= 12 {
-            let month_days = Self::days_in_month(bs_year, bs_month)? as i64;
-            if remaining_days >= month_days {
-                remaining_days -= month_days;
-                bs_month += 1;
-            } else {
-                break;
+        let mut year = from.year;
+        loop {
+            let candidate = NepaliDate {
+                year,
+                month,
+                day: day.min(Self::days_in_month(year, month)?),
+            };
+            if candidate >= from {
+                return Ok(candidate);
             }
+            year += 1;
         }
-
-        let bs_day = (remaining_days + 1) as u8;
-        Self::new(bs_year, bs_month, bs_day)
     }
 
     /// Returns the ordinal representation of the date (days since 1975-01-01 BS)
-    /// 1975-01-01 BS is ordinal 1.
+    /// 1975-01-01 BS is ordinal 1; earlier dates count down from 0.
+    ///
+    /// # Panics
+    /// Panics if the date is invalid or a year between it and the epoch is
+    /// not covered by the calendar data; [`NepaliDate::try_to_ordinal`]
+    /// returns the error instead.
     pub fn to_ordinal(&self) -> i32 {
-        let mut total_days = 0;
+        match self.try_to_ordinal() {
+            Ok(ordinal) => ordinal,
+            Err(e) => panic!("no ordinal for {:?}: {}", self, e),
+        }
+    }
 
+    /// Like [`NepaliDate::to_ordinal`], but returns an error if the date is
+    /// invalid or any year between it and the epoch is not covered by the
+    /// calendar data
+    pub fn try_to_ordinal(&self) -> Result<i32> {
+        Self::new(self.year, self.month, self.day)?;
+
+        let mut total_days = 0;
+        for y in self.year..BS_EPOCH_YEAR {
+            total_days -= Self::days_in_year(y)? as i32;
+        }
         for y in BS_EPOCH_YEAR..self.year {
-            for m in 1..=12 {
-                total_days += Self::days_in_month(y, m).unwrap_or(30) as i32;
-            }
+            total_days += Self::days_in_year(y)? as i32;
         }
-
         for m in 1..self.month {
-            total_days += Self::days_in_month(self.year, m).unwrap_or(30) as i32;
+            total_days += Self::days_in_month(self.year, m)? as i32;
         }
 
-        total_days += self.day as i32;
-        total_days
+        Ok(total_days + self.day as i32)
     }
 
     /// Creates a NepaliDate from an ordinal (days since 1975-01-01 BS)
+    ///
+    /// Ordinals of 0 or below name dates before the epoch. They resolve
+    /// when the active calendar source covers those years (the
+    /// `astronomical` feature) and fail with `BeforeEpoch` otherwise.
     pub fn from_ordinal(ordinal: i32) -> Result<Self> {
-        if ordinal < 1 {
-            return Err(NpdatetimeError::InvalidDate(
-                "Ordinal must be at least 1".to_string(),
-            ));
-        }
-
         let mut remaining_days = (ordinal - 1) as i64;
         let mut bs_year = BS_EPOCH_YEAR;
         let mut bs_month = 1u8;
 
+        while remaining_days < 0 {
+            bs_year -= 1;
+            remaining_days += Self::days_in_year(bs_year)? as i64;
+        }
+
         loop {
             let mut year_days = 0;
             for m in 1..=12 {
@@ -237,18 +422,50 @@ impl NepaliDate {
         Self::new(bs_year, bs_month, bs_day)
     }
 
-    /// Returns today's date in Nepali calendar
+    /// Returns today's date in Nepali calendar (civil day in Nepal Standard Time)
     pub fn today() -> Result<Self> {
+        Self::today_in_offset(NPT_OFFSET_SECONDS)
+    }
+
+    /// Returns today's date for a timezone `offset_seconds` east of UTC
+    pub fn today_in_offset(offset_seconds: i32) -> Result<Self> {
         use std::time::{SystemTime, UNIX_EPOCH};
 
-        let duration = SystemTime::now().duration_since(UNIX_EPOCH).unwrap();
+        let duration = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map_err(|e| NpdatetimeError::CalculationError(format!("System clock error: {}", e)))?;
 
-        let days_since_unix_epoch = duration.as_secs() / 86400;
-        let (year, month, day) = unix_epoch_to_gregorian(days_since_unix_epoch);
+        Self::from_unix_timestamp(duration.as_secs() as i64, offset_seconds)
+    }
+
+    /// Creates a Nepali date from a Unix timestamp (seconds since 1970-01-01 UTC)
+    ///
+    /// The civil day is taken in the timezone `offset_seconds` east of UTC,
+    /// e.g. [`NPT_OFFSET_SECONDS`] for Nepal.
+    pub fn from_unix_timestamp(timestamp: i64, offset_seconds: i32) -> Result<Self> {
+        let local_seconds = timestamp
+            .checked_add(offset_seconds as i64)
+            .ok_or_else(|| {
+                NpdatetimeError::OutOfRange(format!(
+                    "Timestamp {} with offset {}s overflows",
+                    timestamp, offset_seconds
+                ))
+            })?;
+        let local_days = local_seconds.div_euclid(86400);
+        let (year, month, day) = days_to_gregorian(gregorian_to_days(1970, 1, 1) + local_days);
 
         Self::from_gregorian(year, month, day)
     }
 
+    /// Returns the Unix timestamp of midnight Nepal Standard Time on this date
+    ///
+    /// This is the inverse of [`NepaliDate::from_unix_timestamp`] with
+    /// [`NPT_OFFSET_SECONDS`].
+    pub fn to_unix_timestamp(&self) -> Result<i64> {
+        let days = self.gregorian_day_number()? - gregorian_to_days(1970, 1, 1);
+        Ok(days * 86400 - NPT_OFFSET_SECONDS as i64)
+    }
+
     /// Returns the Nepali Fiscal Year for the date.
     /// In Nepal, the fiscal year starts on Shrawan 1.
     /// Returns a string like "2080/81"
@@ -262,6 +479,14 @@ impl NepaliDate {
         }
     }
 
+    /// Returns the Nepali Fiscal Year in Devanagari numerals, like "२०८०/८१"
+    ///
+    /// This is the form printed in government reports; see
+    /// [`NepaliDate::fiscal_year`] for the ASCII one.
+    pub fn fiscal_year_unicode(&self) -> String {
+        to_devanagari_digits(&self.fiscal_year())
+    }
+
     /// Returns the fiscal quarter (1-4)
     /// Q1: Shrawan, Bhadra, Ashwin
     /// Q2: Kartik, Mangsir, Poush
@@ -284,21 +509,170 @@ impl NepaliDate {
             .replace("%m", &format!("{:02}", self.month))
             .replace("%d", &format!("{:02}", self.day))
             .replace("%B", NEPALI_MONTHS[(self.month - 1) as usize])
-            .replace("%b", &NEPALI_MONTHS[(self.month - 1) as usize][..3])
+            .replace("%b", &self.month_name_short())
     }
 
     /// Adds days to the date
+    ///
+    /// Counts on the BS ordinal, so no Gregorian conversion is involved.
     pub fn add_days(&self, days: i32) -> Result<Self> {
-        let (g_year, g_month, g_day) = self.to_gregorian()?;
-        let total_days = gregorian_to_days(g_year, g_month, g_day) + days as i64;
-        let (new_year, new_month, new_day) = days_to_gregorian(total_days);
-        Self::from_gregorian(new_year, new_month, new_day)
+        let ordinal = self.try_to_ordinal()?.checked_add(days).ok_or_else(|| {
+            NpdatetimeError::OutOfRange(format!("Adding {} days to {} overflows", days, self))
+        })?;
+        Self::from_ordinal(ordinal)
+    }
+
+    /// Returns the number of days from this date to `other`
+    ///
+    /// The result is negative when `other` is earlier.
+    pub fn days_between(&self, other: &NepaliDate) -> Result<i64> {
+        Ok(other.gregorian_day_number()? - self.gregorian_day_number()?)
+    }
+
+    /// Returns the number of whole months elapsed from this date to `other`
+    ///
+    /// A month counts as complete once `other` reaches the same day of month,
+    /// or the last day of its month if that month is shorter (so Jestha 32 to
+    /// Ashadh 31 is one month). The result is negative when `other` is earlier.
+    pub fn months_between(&self, other: &NepaliDate) -> Result<i32> {
+        if other < self {
+            return other.months_between(self).map(|months| -months);
+        }
+
+        let mut months = (other.year - self.year) * 12 + other.month as i32 - self.month as i32;
+        let threshold = self.day.min(Self::days_in_month(other.year, other.month)?);
+        if other.day < threshold {
+            months -= 1;
+        }
+        Ok(months)
+    }
+
+    /// Returns the number of whole years elapsed from this date to `other`
+    ///
+    /// Follows the same rules as [`NepaliDate::months_between`].
+    pub fn years_between(&self, other: &NepaliDate) -> Result<i32> {
+        Ok(self.months_between(other)? / 12)
+    }
+
+    /// Adds days to the date, returning `None` if the result falls outside
+    /// [`NepaliDate::MIN`]..=[`NepaliDate::MAX`]
+    pub fn checked_add_days(&self, days: i32) -> Option<Self> {
+        let target = self.gregorian_day_number().ok()? + days as i64;
+        let (min, max) = Self::supported_day_numbers();
+        if target < min || target > max {
+            return None;
+        }
+
+        let (year, month, day) = days_to_gregorian(target);
+        Self::from_gregorian(year, month, day).ok()
+    }
+
+    /// Adds days to the date, clamping the result to
+    /// [`NepaliDate::MIN`]..=[`NepaliDate::MAX`] instead of failing
+    pub fn saturating_add_days(&self, days: i32) -> Self {
+        let boundary = if days < 0 { Self::MIN } else { Self::MAX };
+        let Ok(current) = self.gregorian_day_number() else {
+            return boundary;
+        };
+
+        let (min, max) = Self::supported_day_numbers();
+        let (year, month, day) = days_to_gregorian((current + days as i64).clamp(min, max));
+        Self::from_gregorian(year, month, day).unwrap_or(boundary)
+    }
+
+    fn gregorian_day_number(&self) -> Result<i64> {
+        let (year, month, day) = self.to_gregorian()?;
+        Ok(gregorian_to_days(year, month, day))
+    }
+
+    /// Gregorian day numbers of [`NepaliDate::MIN`] and [`NepaliDate::MAX`]
+    fn supported_day_numbers() -> (i64, i64) {
+        let (ey, em, ed) = BS_EPOCH_AD;
+        let min = gregorian_to_days(ey, em, ed);
+        (min, min + Self::MAX.to_ordinal() as i64 - 1)
+    }
+}
+
+#[cfg(feature = "astronomical")]
+impl NepaliDate {
+    /// Converts the civil date to a Julian Day at local noon (NPT), expressed in UTC
+    ///
+    /// This matches the convention of [`crate::astronomical::BsDate::to_julian_day`]
+    /// so civil dates can be fed directly into Tithi/Sankranti calculations.
+    pub fn to_julian_day(&self) -> Result<crate::astronomical::core::JulianDay> {
+        use crate::astronomical::core::{JulianDay, time::npt_to_utc};
+
+        let (year, month, day) = self.to_gregorian()?;
+        Ok(npt_to_utc(JulianDay::from_gregorian(
+            year, month, day, 12.0,
+        )))
+    }
+
+    /// Creates a civil date from the NPT calendar day containing a Julian Day (UTC)
+    pub fn from_julian_day(jd: crate::astronomical::core::JulianDay) -> Result<Self> {
+        use crate::astronomical::core::time::utc_to_npt;
+
+        let (year, month, day, _) = utc_to_npt(jd).to_gregorian();
+        Self::from_gregorian(year, month, day)
+    }
+
+    /// Returns true if the astronomical date names the same year, month and day
+    ///
+    /// The Adhika flag of `bs` is ignored: the civil calendar has no leap
+    /// months, so only the numbered date is compared.
+    pub fn matches_astronomical(&self, bs: &crate::astronomical::BsDate) -> bool {
+        self.year == bs.year && self.month == bs.month && self.day == bs.day
+    }
+}
+
+#[cfg(feature = "astronomical")]
+impl PartialEq<crate::astronomical::BsDate> for NepaliDate {
+    fn eq(&self, other: &crate::astronomical::BsDate) -> bool {
+        self.matches_astronomical(other)
+    }
+}
+
+#[cfg(feature = "astronomical")]
+impl PartialEq<NepaliDate> for crate::astronomical::BsDate {
+    fn eq(&self, other: &NepaliDate) -> bool {
+        other.matches_astronomical(self)
+    }
+}
+
+/// Interprets the value as an ordinal, as returned by [`NepaliDate::to_ordinal`]
+///
+/// The conversion is 1-based: only ordinals between [`NepaliDate::MIN`] (1)
+/// and [`NepaliDate::MAX`] are accepted and anything else is `OutOfRange`.
+/// Unlike [`NepaliDate::from_ordinal`], 0 and negative ordinals are rejected
+/// even when the `astronomical` feature could resolve them, so the accepted
+/// range does not depend on the enabled features.
+impl TryFrom<i32> for NepaliDate {
+    type Error = NpdatetimeError;
+
+    fn try_from(ordinal: i32) -> Result<Self> {
+        let max = Self::MAX.to_ordinal();
+        if !(1..=max).contains(&ordinal) {
+            return Err(NpdatetimeError::OutOfRange(format!(
+                "Ordinal {} is outside the supported range 1..={}",
+                ordinal, max
+            )));
+        }
+        Self::from_ordinal(ordinal)
     }
 }
 
 impl fmt::Display for NepaliDate {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "{}-{:02}-{:02}", self.year, self.month, self.day)
+        // Catch sentinels such as 0000-00-00 in tests instead of printing them
+        debug_assert!(
+            (1..=12).contains(&self.month) && self.day >= 1,
+            "invalid date {}-{:02}-{:02}",
+            self.year,
+            self.month,
+            self.day
+        );
+        // Pad the whole date so width/fill/alignment flags apply as a unit
+        f.pad(&format!("{}-{:02}-{:02}", self.year, self.month, self.day))
     }
 }
 
@@ -323,6 +697,26 @@ pub fn gregorian_days_in_month(year: i32, month: u8) -> u8 {
     }
 }
 
+/// Checks that a Gregorian date exists (month 1-12, day within the month)
+pub fn validate_gregorian(year: i32, month: u8, day: u8) -> Result<()> {
+    if !(1..=12).contains(&month) {
+        return Err(NpdatetimeError::InvalidDate(format!(
+            "Gregorian month must be between 1 and 12, got {}",
+            month
+        )));
+    }
+
+    let max_day = gregorian_days_in_month(year, month);
+    if day < 1 || day > max_day {
+        return Err(NpdatetimeError::InvalidDate(format!(
+            "Day must be between 1 and {} for {}-{:02}, got {}",
+            max_day, year, month, day
+        )));
+    }
+
+    Ok(())
+}
+
 pub fn gregorian_days_since_epoch(
     year: i32,
     month: u8,
@@ -332,7 +726,7 @@ pub fn gregorian_days_since_epoch(
     let (ey, em, ed) = epoch;
 
     if year < ey || (year == ey && month < em) || (year == ey && month == em && day < ed) {
-        return Err(NpdatetimeError::OutOfRange(
+        return Err(NpdatetimeError::BeforeEpoch(
             "Date is before the BS epoch".to_string(),
         ));
     }
@@ -405,6 +799,121 @@ pub fn unix_epoch_to_gregorian(days_since_epoch: u64) -> (i32, u8, u8) {
 mod tests {
     use super::*;
 
+    #[cfg(any(feature = "lookup-tables", feature = "astronomical"))]
+    #[test]
+    fn test_days_remaining() {
+        // 2081: 31,32,31,32,31,30,30,30,29,30,29,31 (366 days)
+        let mid = NepaliDate::new(2081, 5, 10).unwrap();
+        assert_eq!(mid.days_remaining_in_month().unwrap(), 21);
+        assert_eq!(
+            mid.days_remaining_in_year().unwrap(),
+            366 - mid.day_of_year().unwrap()
+        );
+
+        let month_end = NepaliDate::new(2081, 2, 32).unwrap();
+        assert_eq!(month_end.days_remaining_in_month().unwrap(), 0);
+
+        let year_end = NepaliDate::new(2081, 12, 31).unwrap();
+        assert_eq!(year_end.days_remaining_in_month().unwrap(), 0);
+        assert_eq!(year_end.days_remaining_in_year().unwrap(), 0);
+
+        // Fields set past the month's end are an error, not an underflow
+        let past_end = NepaliDate::from_ymd_unchecked(2081, 12, 32);
+        assert!(matches!(
+            past_end.days_remaining_in_month(),
+            Err(NpdatetimeError::InvalidDate(_))
+        ));
+        assert!(matches!(
+            past_end.days_remaining_in_year(),
+            Err(NpdatetimeError::InvalidDate(_))
+        ));
+    }
+
+    #[test]
+    fn test_comparison_predicates_across_year_end() {
+        let last = NepaliDate::from_ymd_unchecked(2080, 12, 30);
+        let first = NepaliDate::from_ymd_unchecked(2081, 1, 1);
+
+        assert!(last.is_before(&first));
+        assert!(!first.is_before(&last));
+        assert!(first.is_after(&last));
+        assert!(!last.is_after(&first));
+        assert!(!last.is_before(&last) && !last.is_after(&last));
+        assert!(first.is_same_day(&NepaliDate::from_ymd_unchecked(2081, 1, 1)));
+        assert!(!first.is_same_day(&last));
+    }
+
+    #[cfg(any(feature = "lookup-tables", feature = "astronomical"))]
+    #[test]
+    fn test_within_days() {
+        let last = NepaliDate::new(2080, 12, 30).unwrap();
+        let first = NepaliDate::new(2081, 1, 1).unwrap();
+
+        assert!(last.within_days(&first, 1));
+        assert!(first.within_days(&last, 1));
+        assert!(!last.within_days(&first, 0));
+        assert!(first.within_days(&first, 0));
+        assert!(!last.within_days(&first.add_days(1).unwrap(), 1));
+    }
+
+    #[test]
+    fn test_fiscal_year_ascii_and_devanagari() {
+        let shrawan = NepaliDate::from_ymd_unchecked(2080, 4, 1);
+        let ashadh = NepaliDate::from_ymd_unchecked(2080, 3, 31);
+
+        assert_eq!(shrawan.fiscal_year(), "2080/81");
+        assert_eq!(shrawan.fiscal_year_unicode(), "२०८०/८१");
+        assert_eq!(ashadh.fiscal_year(), "2079/80");
+        assert_eq!(ashadh.fiscal_year_unicode(), "२०७९/८०");
+    }
+
+    const DASHAIN_2081: NepaliDate = NepaliDate::from_ymd_unchecked(2081, 6, 27);
+    static BUDGET_DAY: NepaliDate = NepaliDate::from_ymd_unchecked(2081, 2, 15);
+
+    #[test]
+    fn test_const_construction() {
+        assert_eq!(
+            (DASHAIN_2081.year, DASHAIN_2081.month, DASHAIN_2081.day),
+            (2081, 6, 27)
+        );
+        assert!(BUDGET_DAY < DASHAIN_2081);
+        assert_eq!(NepaliDate::MIN.to_string(), "1975-01-01");
+    }
+
+    #[cfg(any(feature = "lookup-tables", feature = "astronomical"))]
+    #[test]
+    fn test_unchecked_matches_validated() {
+        assert_eq!(DASHAIN_2081, NepaliDate::new(2081, 6, 27).unwrap());
+    }
+
+    #[cfg(debug_assertions)]
+    #[test]
+    #[should_panic(expected = "month must be 1-12")]
+    fn test_unchecked_debug_asserts_month() {
+        let _ = NepaliDate::from_ymd_unchecked(2081, 13, 1);
+    }
+
+    #[cfg(debug_assertions)]
+    #[test]
+    #[should_panic(expected = "invalid date 0-00-00")]
+    fn test_display_debug_asserts_sentinel() {
+        let sentinel = NepaliDate {
+            year: 0,
+            month: 0,
+            day: 0,
+        };
+        let _ = sentinel.to_string();
+    }
+
+    #[test]
+    fn test_display_valid_dates() {
+        assert_eq!(
+            NepaliDate::from_ymd_unchecked(2081, 12, 31).to_string(),
+            "2081-12-31"
+        );
+        assert_eq!(format!("{:>12}", NepaliDate::MIN), "  1975-01-01");
+    }
+
     #[cfg(any(feature = "lookup-tables", feature = "astronomical"))]
     #[test]
     fn test_create_valid_date() {
@@ -414,12 +923,52 @@ mod tests {
         assert_eq!(date.day, 19);
     }
 
+    #[cfg(feature = "lookup-tables")]
+    #[test]
+    fn test_new_clamped() {
+        // 2081 Jestha has 32 days, Ashwin has 30
+        assert_eq!(
+            NepaliDate::new_clamped(2081, 2, 99).unwrap(),
+            NepaliDate::new(2081, 2, 32).unwrap()
+        );
+        assert_eq!(
+            NepaliDate::new_clamped(2081, 6, 31).unwrap(),
+            NepaliDate::new(2081, 6, 30).unwrap()
+        );
+        assert_eq!(
+            NepaliDate::new_clamped(2081, 0, 0).unwrap(),
+            NepaliDate::new(2081, 1, 1).unwrap()
+        );
+        assert_eq!(NepaliDate::new_clamped(2081, 13, 15).unwrap().month, 12);
+        if cfg!(not(feature = "astronomical")) {
+            assert!(NepaliDate::new_clamped(1900, 1, 1).is_err());
+        }
+    }
+
     #[test]
     fn test_invalid_month() {
         assert!(NepaliDate::new(2077, 13, 1).is_err());
         assert!(NepaliDate::new(2077, 0, 1).is_err());
     }
 
+    #[cfg(feature = "lookup-tables")]
+    #[test]
+    fn test_32_day_months() {
+        // 2081 Jestha has 32 days, Ashwin has 30
+        assert_eq!(NepaliDate::days_in_month(2081, 2).unwrap(), 32);
+        assert!(NepaliDate::new(2081, 2, 32).is_ok());
+        assert!(NepaliDate::new(2081, 2, 33).is_err());
+
+        assert_eq!(NepaliDate::days_in_month(2081, 6).unwrap(), 30);
+        assert!(NepaliDate::new(2081, 6, 31).is_err());
+
+        let last = NepaliDate::parse("2081-02-32", "%Y-%m-%d").unwrap();
+        assert_eq!(
+            last.add_days(1).unwrap(),
+            NepaliDate::new(2081, 3, 1).unwrap()
+        );
+    }
+
     #[cfg(any(feature = "lookup-tables", feature = "astronomical"))]
     #[test]
     fn test_conversion_to_gregorian() {
@@ -437,6 +986,209 @@ mod tests {
         assert_eq!(bs_date.day, 1);
     }
 
+    #[test]
+    fn test_from_gregorian_rejects_impossible_dates() {
+        for (y, m, d) in [
+            (2020, 2, 30),
+            (2023, 2, 29),
+            (2020, 13, 1),
+            (2020, 4, 31),
+            (2020, 1, 0),
+        ] {
+            assert!(
+                matches!(
+                    NepaliDate::from_gregorian(y, m, d),
+                    Err(NpdatetimeError::InvalidDate(_))
+                ),
+                "{}-{}-{}",
+                y,
+                m,
+                d
+            );
+        }
+    }
+
+    #[test]
+    fn test_before_epoch_error() {
+        let result = NepaliDate::from_gregorian(1900, 1, 1);
+        assert!(matches!(result, Err(NpdatetimeError::BeforeEpoch(_))));
+    }
+
+    #[cfg(all(feature = "lookup-tables", not(feature = "astronomical")))]
+    #[test]
+    fn test_after_supported_range_error() {
+        let result = NepaliDate::new(2200, 1, 1);
+        assert!(matches!(
+            result,
+            Err(NpdatetimeError::AfterSupportedRange(_))
+        ));
+    }
+
+    #[cfg(any(feature = "lookup-tables", feature = "astronomical"))]
+    #[test]
+    fn test_checked_and_saturating_add_days() {
+        let date = NepaliDate::new(2080, 1, 1).unwrap();
+        assert_eq!(date.checked_add_days(i32::MAX), None);
+        assert_eq!(date.checked_add_days(i32::MIN), None);
+        assert_eq!(date.checked_add_days(1), date.add_days(1).ok());
+
+        assert_eq!(date.saturating_add_days(i32::MAX), NepaliDate::MAX);
+        assert_eq!(date.saturating_add_days(i32::MIN), NepaliDate::MIN);
+        assert_eq!(date.saturating_add_days(-1), date.add_days(-1).unwrap());
+
+        assert_eq!(NepaliDate::MIN.to_gregorian().unwrap(), BS_EPOCH_AD);
+        assert_eq!(NepaliDate::MAX.saturating_add_days(1), NepaliDate::MAX);
+        assert!(NepaliDate::new(2100, 12, 30).is_ok());
+    }
+
+    #[cfg(any(feature = "lookup-tables", feature = "astronomical"))]
+    #[test]
+    fn test_from_gregorian_iter() {
+        let input = vec![(1943, 4, 14), (2020, 9, 4), (1900, 1, 1)];
+        let results: Vec<_> = NepaliDate::from_gregorian_iter(input.into_iter()).collect();
+
+        assert_eq!(results.len(), 3);
+        assert_eq!(results[0], NepaliDate::new(2000, 1, 1));
+        assert_eq!(results[1], NepaliDate::new(2077, 5, 19));
+        assert!(matches!(results[2], Err(NpdatetimeError::BeforeEpoch(_))));
+    }
+
+    #[cfg(any(feature = "lookup-tables", feature = "astronomical"))]
+    #[test]
+    fn test_days_between() {
+        let a = NepaliDate::new(2077, 5, 19).unwrap();
+        let b = NepaliDate::new(2078, 5, 19).unwrap();
+        assert_eq!(a.days_between(&b).unwrap(), 365);
+        assert_eq!(b.days_between(&a).unwrap(), -365);
+        assert_eq!(a.days_between(&a).unwrap(), 0);
+    }
+
+    #[cfg(feature = "lookup-tables")]
+    #[test]
+    fn test_is_long_year() {
+        assert!(NepaliDate::is_long_year(1976).unwrap());
+        assert_eq!(NepaliDate::days_in_year(1976).unwrap(), 366);
+        assert!(!NepaliDate::is_long_year(1975).unwrap());
+        assert_eq!(NepaliDate::days_in_year(1975).unwrap(), 365);
+    }
+
+    #[cfg(feature = "lookup-tables")]
+    #[test]
+    fn test_months_and_years_between() {
+        let start = NepaliDate::new(2081, 1, 15).unwrap();
+        let months = |y, m, d| {
+            start
+                .months_between(&NepaliDate::new(y, m, d).unwrap())
+                .unwrap()
+        };
+
+        assert_eq!(months(2081, 2, 15), 1);
+        assert_eq!(months(2081, 2, 14), 0);
+        assert_eq!(months(2081, 1, 15), 0);
+        assert_eq!(months(2082, 1, 14), 11);
+        assert_eq!(months(2082, 1, 15), 12);
+        assert_eq!(months(2080, 12, 15), -1);
+        assert_eq!(months(2080, 12, 16), 0);
+
+        // Jestha 32 to the last day of the 31-day Ashadh is a whole month
+        let jestha_end = NepaliDate::new(2081, 2, 32).unwrap();
+        let ashadh_end = NepaliDate::new(2081, 3, 31).unwrap();
+        assert_eq!(jestha_end.months_between(&ashadh_end).unwrap(), 1);
+
+        let later = NepaliDate::new(2083, 1, 14).unwrap();
+        assert_eq!(start.years_between(&later).unwrap(), 1);
+        assert_eq!(later.years_between(&start).unwrap(), -1);
+        assert_eq!(
+            start
+                .years_between(&NepaliDate::new(2083, 1, 15).unwrap())
+                .unwrap(),
+            2
+        );
+    }
+
+    #[cfg(feature = "lookup-tables")]
+    #[test]
+    fn test_next_anniversary() {
+        let from = NepaliDate::new(2081, 5, 10).unwrap();
+        assert_eq!(
+            NepaliDate::next_anniversary(5, 10, from).unwrap(),
+            from,
+            "same day counts"
+        );
+        assert_eq!(
+            NepaliDate::next_anniversary(5, 20, from).unwrap(),
+            NepaliDate::new(2081, 5, 20).unwrap()
+        );
+
+        // Jestha 32: 2082 Jestha has only 31 days, 2085 Jestha has 32
+        assert_eq!(
+            NepaliDate::next_anniversary(2, 32, from).unwrap(),
+            NepaliDate::new(2082, 2, 31).unwrap()
+        );
+        let from = NepaliDate::new(2084, 12, 1).unwrap();
+        assert_eq!(
+            NepaliDate::next_anniversary(2, 32, from).unwrap(),
+            NepaliDate::new(2085, 2, 32).unwrap()
+        );
+
+        assert!(NepaliDate::next_anniversary(13, 1, from).is_err());
+    }
+
+    #[cfg(any(feature = "lookup-tables", feature = "astronomical"))]
+    #[test]
+    fn test_cmp_gregorian() {
+        let date = NepaliDate::new(2000, 1, 1).unwrap();
+        assert_eq!(date.cmp_gregorian(1943, 4, 13).unwrap(), Ordering::Greater);
+        assert_eq!(date.cmp_gregorian(1943, 4, 14).unwrap(), Ordering::Equal);
+        assert_eq!(date.cmp_gregorian(1943, 4, 15).unwrap(), Ordering::Less);
+    }
+
+    #[cfg(any(feature = "lookup-tables", feature = "astronomical"))]
+    #[test]
+    fn test_from_unix_timestamp() {
+        // 2020-09-04 00:00:00 UTC
+        let date = NepaliDate::from_unix_timestamp(1_599_177_600, 0).unwrap();
+        assert_eq!(date, NepaliDate::new(2077, 5, 19).unwrap());
+
+        // 2020-09-03 18:30 UTC is already 00:15 on 2020-09-04 in Nepal
+        let utc = NepaliDate::from_unix_timestamp(1_599_157_800, 0).unwrap();
+        let npt = NepaliDate::from_unix_timestamp(1_599_157_800, NPT_OFFSET_SECONDS).unwrap();
+        assert_eq!(utc, NepaliDate::new(2077, 5, 18).unwrap());
+        assert_eq!(npt, NepaliDate::new(2077, 5, 19).unwrap());
+
+        // Applying the offset must not overflow
+        for (timestamp, offset) in [(i64::MAX, NPT_OFFSET_SECONDS), (i64::MIN, -1)] {
+            assert!(matches!(
+                NepaliDate::from_unix_timestamp(timestamp, offset),
+                Err(NpdatetimeError::OutOfRange(_))
+            ));
+        }
+    }
+
+    #[cfg(any(feature = "lookup-tables", feature = "astronomical"))]
+    #[test]
+    fn test_to_unix_timestamp() {
+        // 2020-09-04 00:00 NPT is 2020-09-03 18:15 UTC
+        let date = NepaliDate::new(2077, 5, 19).unwrap();
+        let timestamp = date.to_unix_timestamp().unwrap();
+        assert_eq!(timestamp, 1_599_177_600 - NPT_OFFSET_SECONDS as i64);
+        assert_eq!(
+            NepaliDate::from_unix_timestamp(timestamp, NPT_OFFSET_SECONDS).unwrap(),
+            date
+        );
+        // One second earlier is still the previous civil day
+        assert_eq!(
+            NepaliDate::from_unix_timestamp(timestamp - 1, NPT_OFFSET_SECONDS).unwrap(),
+            date.add_days(-1).unwrap()
+        );
+    }
+
+    #[cfg(any(feature = "lookup-tables", feature = "astronomical"))]
+    #[test]
+    fn test_today_is_in_range() {
+        assert!(NepaliDate::today().is_ok());
+    }
+
     #[cfg(any(feature = "lookup-tables", feature = "astronomical"))]
     #[test]
     fn test_format() {
@@ -450,6 +1202,216 @@ mod tests {
     fn test_display() {
         let date = NepaliDate::new(2077, 5, 19).unwrap();
         assert_eq!(format!("{}", date), "2077-05-19");
+        assert_eq!(format!("{:>12}", date), "  2077-05-19");
+        assert_eq!(format!("{:*<12}", date), "2077-05-19**");
+        assert_eq!(format!("{:^14}|", date), "  2077-05-19  |");
+    }
+
+    #[cfg(feature = "astronomical")]
+    #[test]
+    fn test_julian_day_bridge() {
+        use crate::astronomical::BsDate;
+
+        let civil = NepaliDate::new(2081, 1, 1).unwrap();
+        let civil_jd = civil.to_julian_day().unwrap();
+        let astro_jd = BsDate::new(2081, 1, 1).unwrap().to_julian_day().unwrap();
+        assert!(civil_jd.approx_eq(&astro_jd, 1.0));
+
+        assert_eq!(NepaliDate::from_julian_day(civil_jd).unwrap(), civil);
+    }
+
+    /// The month-by-month Gregorian walk `to_gregorian` used before it
+    /// switched to day numbers
+    #[cfg(feature = "lookup-tables")]
+    fn to_gregorian_by_walking(date: &NepaliDate) -> (i32, u8, u8) {
+        let mut days_to_add = (date.to_ordinal() - 1) as i64;
+        let (mut year, mut month, mut day) = BS_EPOCH_AD;
+        while days_to_add > 0 {
+            let days_in_current_month = gregorian_days_in_month(year, month);
+            if days_to_add >= (days_in_current_month - day + 1) as i64 {
+                days_to_add -= (days_in_current_month - day + 1) as i64;
+                day = 1;
+                month += 1;
+                if month > 12 {
+                    month = 1;
+                    year += 1;
+                }
+            } else {
+                day += days_to_add as u8;
+                days_to_add = 0;
+            }
+        }
+        (year, month, day)
+    }
+
+    #[cfg(feature = "lookup-tables")]
+    #[test]
+    fn test_to_gregorian_matches_walk_over_whole_range() {
+        use crate::core::range::NepaliDateRange;
+
+        for date in NepaliDateRange::new(NepaliDate::MIN, NepaliDate::MAX) {
+            assert_eq!(
+                date.to_gregorian().unwrap(),
+                to_gregorian_by_walking(&date),
+                "{}",
+                date
+            );
+        }
+    }
+
+    #[cfg(any(feature = "lookup-tables", feature = "astronomical"))]
+    #[test]
+    fn test_normalize_rolls_over() {
+        // 2081: Baisakh 31, Jestha 32 days
+        assert_eq!(
+            NepaliDate::normalize(2081, 1, 45).unwrap(),
+            NepaliDate::new(2081, 2, 14).unwrap()
+        );
+        assert_eq!(
+            NepaliDate::normalize(2081, 1, 70).unwrap(),
+            NepaliDate::new(2081, 3, 7).unwrap()
+        );
+        assert_eq!(
+            NepaliDate::normalize(2080, 14, 3).unwrap(),
+            NepaliDate::new(2081, 2, 3).unwrap()
+        );
+        assert_eq!(
+            NepaliDate::normalize(2081, 12, 40).unwrap(),
+            NepaliDate::new(2082, 1, 9).unwrap()
+        );
+        assert_eq!(
+            NepaliDate::normalize(2081, 0, 5).unwrap(),
+            NepaliDate::new(2080, 12, 5).unwrap()
+        );
+        assert_eq!(
+            NepaliDate::normalize(2081, 2, 0).unwrap(),
+            NepaliDate::new(2081, 1, 31).unwrap()
+        );
+
+        let valid = NepaliDate::new(2077, 5, 19).unwrap();
+        assert_eq!(NepaliDate::normalize(2077, 5, 19).unwrap(), valid);
+    }
+
+    #[cfg(feature = "astronomical")]
+    #[test]
+    fn test_normalize_rejects_zero_length_month() {
+        // Far outside the astronomical search, months come back with 0 days
+        assert_eq!(NepaliDate::days_in_month(100_000, 1).unwrap(), 0);
+        assert!(matches!(
+            NepaliDate::normalize(100_000, 1, 5),
+            Err(NpdatetimeError::InvalidDate(_))
+        ));
+    }
+
+    #[cfg(any(feature = "lookup-tables", feature = "astronomical"))]
+    #[test]
+    fn test_from_ordinal_around_epoch() {
+        assert_eq!(NepaliDate::from_ordinal(1).unwrap(), NepaliDate::MIN);
+
+        let date = NepaliDate::new(2077, 5, 19).unwrap();
+        assert_eq!(NepaliDate::from_ordinal(date.to_ordinal()).unwrap(), date);
+
+        if cfg!(feature = "astronomical") {
+            let last_of_1974 = NepaliDate::from_ordinal(0).unwrap();
+            assert_eq!((last_of_1974.year, last_of_1974.month), (1974, 12));
+            assert_eq!(
+                last_of_1974.day,
+                NepaliDate::days_in_month(1974, 12).unwrap()
+            );
+            assert_eq!(last_of_1974.to_ordinal(), 0);
+
+            let earlier = NepaliDate::from_ordinal(-400).unwrap();
+            assert_eq!(earlier.year, 1973);
+            assert_eq!(earlier.to_ordinal(), -400);
+        } else {
+            for ordinal in [0, -400] {
+                assert!(matches!(
+                    NepaliDate::from_ordinal(ordinal),
+                    Err(NpdatetimeError::BeforeEpoch(_))
+                ));
+            }
+        }
+    }
+
+    #[cfg(any(feature = "lookup-tables", feature = "astronomical"))]
+    #[test]
+    fn test_try_to_ordinal() {
+        for date in [
+            NepaliDate::MIN,
+            NepaliDate::new(2077, 5, 19).unwrap(),
+            NepaliDate::MAX,
+        ] {
+            assert_eq!(date.try_to_ordinal().unwrap(), date.to_ordinal());
+        }
+
+        let past_end = NepaliDate::from_ymd_unchecked(2081, 1, 32);
+        assert!(matches!(
+            past_end.try_to_ordinal(),
+            Err(NpdatetimeError::InvalidDate(_))
+        ));
+        assert!(past_end.add_days(1).is_err());
+
+        let pre_epoch = NepaliDate::from_ymd_unchecked(1974, 12, 1);
+        if cfg!(feature = "astronomical") {
+            assert_eq!(pre_epoch.try_to_ordinal().unwrap(), pre_epoch.to_ordinal());
+        } else {
+            assert!(matches!(
+                pre_epoch.try_to_ordinal(),
+                Err(NpdatetimeError::BeforeEpoch(_))
+            ));
+        }
+    }
+
+    #[cfg(all(feature = "lookup-tables", not(feature = "astronomical")))]
+    #[test]
+    #[should_panic(expected = "no ordinal for")]
+    fn test_to_ordinal_panics_before_covered_years() {
+        let _ = NepaliDate::from_ymd_unchecked(1974, 12, 1).to_ordinal();
+    }
+
+    #[cfg(any(feature = "lookup-tables", feature = "astronomical"))]
+    #[test]
+    fn test_try_from_ordinal() {
+        assert_eq!(NepaliDate::try_from(1).unwrap(), NepaliDate::MIN);
+
+        let date = NepaliDate::new(2077, 5, 19).unwrap();
+        assert_eq!(NepaliDate::try_from(date.to_ordinal()).unwrap(), date);
+
+        let max = NepaliDate::MAX.to_ordinal();
+        assert_eq!(NepaliDate::try_from(max).unwrap(), NepaliDate::MAX);
+        for ordinal in [0, -5, max + 1, i32::MAX] {
+            assert!(matches!(
+                NepaliDate::try_from(ordinal),
+                Err(NpdatetimeError::OutOfRange(_))
+            ));
+        }
+
+        // from_ordinal reaches before the epoch here, try_from stays 1-based
+        if cfg!(feature = "astronomical") {
+            assert!(NepaliDate::from_ordinal(0).is_ok());
+            assert!(NepaliDate::try_from(0).is_err());
+        }
+    }
+
+    #[cfg(feature = "astronomical")]
+    #[test]
+    fn test_matches_astronomical() {
+        use crate::astronomical::BsDate;
+
+        // Both calendars start 2081 on 2024-04-13
+        let civil = NepaliDate::from_gregorian(2024, 4, 13).unwrap();
+        let astro = BsDate::from_gregorian(2024, 4, 13).unwrap();
+        assert!(civil.matches_astronomical(&astro));
+        assert_eq!(civil, astro);
+        assert_eq!(astro, civil);
+
+        // The published calendar runs Shrawan 2081 to 32 days; the astronomical one starts Bhadra
+        let civil = NepaliDate::from_gregorian(2024, 8, 16).unwrap();
+        let astro = BsDate::from_gregorian(2024, 8, 16).unwrap();
+        assert_eq!(civil, NepaliDate::new(2081, 4, 32).unwrap());
+        assert!(!civil.matches_astronomical(&astro));
+        assert_ne!(civil, astro);
+        assert_ne!(astro, civil);
     }
 
     #[cfg(any(feature = "lookup-tables", feature = "astronomical"))]
@@ -492,6 +1454,34 @@ mod tests {
         assert_eq!(new_date.day, 14);
     }
 
+    #[cfg(any(feature = "lookup-tables", feature = "astronomical"))]
+    #[test]
+    fn test_add_days_matches_gregorian_round_trip() {
+        // The implementation before ordinal arithmetic
+        fn add_days_via_gregorian(date: &NepaliDate, days: i32) -> NepaliDate {
+            let (y, m, d) = date.to_gregorian().unwrap();
+            let (y, m, d) = days_to_gregorian(gregorian_to_days(y, m, d) + days as i64);
+            NepaliDate::from_gregorian(y, m, d).unwrap()
+        }
+
+        let max = NepaliDate::MAX.to_ordinal();
+        for ordinal in (1..=max).step_by(97) {
+            let date = NepaliDate::from_ordinal(ordinal).unwrap();
+            for days in [-1000, -32, -1, 1, 31, 365, 1000] {
+                if (1..=max).contains(&(ordinal + days)) {
+                    assert_eq!(
+                        date.add_days(days).unwrap(),
+                        add_days_via_gregorian(&date, days),
+                        "{} + {} days",
+                        date,
+                        days
+                    );
+                }
+            }
+        }
+        assert!(NepaliDate::MAX.add_days(i32::MAX).is_err());
+    }
+
     #[cfg(any(feature = "lookup-tables", feature = "astronomical"))]
     #[test]
     fn test_add_days_round_trip() {
@@ -1 +1,197 @@
-// NepaliDateTime struct
+//! Nepali date combined with a wall-clock time of day
+
+use crate::core::date::NepaliDate;
+use crate::core::error::{NpdatetimeError, Result};
+use std::fmt;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Nepali words for the part of the day, as shown on clocks and alarms
+const NEPALI_DAY_PERIODS: [&str; 4] = ["बिहान", "दिउँसो", "साँझ", "राति"];
+
+/// A BS date with a time of day in Nepal Standard Time
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct NepaliDateTime {
+    pub date: NepaliDate,
+    pub hour: u8,
+    pub minute: u8,
+    pub second: u8,
+}
+
+impl NepaliDateTime {
+    /// Creates a datetime, validating the time of day (24-hour clock)
+    pub fn new(date: NepaliDate, hour: u8, minute: u8, second: u8) -> Result<Self> {
+        if hour > 23 || minute > 59 || second > 59 {
+            return Err(NpdatetimeError::InvalidDate(format!(
+                "Invalid time {:02}:{:02}:{:02}",
+                hour, minute, second
+            )));
+        }
+
+        Ok(NepaliDateTime {
+            date,
+            hour,
+            minute,
+            second,
+        })
+    }
+
+    /// Time of day as `HH:MM:SS`, the expansion of `%X`
+    fn time_string(&self) -> String {
+        format!("{:02}:{:02}:{:02}", self.hour, self.minute, self.second)
+    }
+
+    /// Hour on a 12-hour clock (1-12)
+    pub fn hour12(&self) -> u8 {
+        match self.hour % 12 {
+            0 => 12,
+            h => h,
+        }
+    }
+
+    /// Nepali word for the part of the day
+    ///
+    /// बिहान 04-11, दिउँसो 12-15, साँझ 16-20 and राति 21-03.
+    pub fn day_period_nepali(&self) -> &'static str {
+        match self.hour {
+            4..=11 => NEPALI_DAY_PERIODS[0],
+            12..=15 => NEPALI_DAY_PERIODS[1],
+            16..=20 => NEPALI_DAY_PERIODS[2],
+            _ => NEPALI_DAY_PERIODS[3],
+        }
+    }
+
+    /// Formats the datetime using a format string
+    ///
+    /// Accepts every specifier of [`NepaliDate::format_date`] plus:
+    /// - `%H` - Hour, 24-hour clock (00-23)
+    /// - `%I` - Hour, 12-hour clock (01-12)
+    /// - `%M` - Minute (00-59)
+    /// - `%S` - Second (00-59)
+    /// - `%p` - `AM` or `PM`
+    /// - `%q` - Nepali part of the day (बिहान, दिउँसो, साँझ, राति)
+    /// - `%X` - Preset time, same as `%H:%M:%S`
+    ///
+    /// `%c` additionally carries the time, as `%A, %d %B %Y %H:%M:%S`.
+    ///
+    /// # Examples:
+    /// ```
+    /// # use npdatetime::{NepaliDate, NepaliDateTime};
+    /// # if cfg!(any(feature = "lookup-tables", feature = "astronomical")) {
+    /// let date = NepaliDate::new(2077, 5, 19).unwrap();
+    /// let dt = NepaliDateTime::new(date, 14, 5, 0).unwrap();
+    /// assert_eq!(dt.format("%Y-%m-%d %I:%M %p"), "2077-05-19 02:05 PM");
+    /// assert_eq!(dt.format("%q %I:%M"), "दिउँसो 02:05");
+    /// # }
+    /// ```
+    pub fn format(&self, format_str: &str) -> String {
+        // Expand the time specifiers first, passing everything else (including
+        // `%%` and `{...}` blocks) through to the date formatter untouched
+        let mut date_format = String::new();
+        let mut chars = format_str.chars().peekable();
+
+        while let Some(ch) = chars.next() {
+            if ch == '{' {
+                let literal: String = chars.clone().take_while(|&c| c != '}').collect();
+                let len = literal.chars().count();
+                date_format.push(ch);
+                if chars.clone().nth(len) == Some('}') {
+                    date_format.push_str(&literal);
+                    date_format.push('}');
+                    chars.nth(len);
+                }
+            } else if ch == '%' {
+                match chars.next() {
+                    Some('H') => date_format.push_str(&format!("{:02}", self.hour)),
+                    Some('I') => date_format.push_str(&format!("{:02}", self.hour12())),
+                    Some('M') => date_format.push_str(&format!("{:02}", self.minute)),
+                    Some('S') => date_format.push_str(&format!("{:02}", self.second)),
+                    Some('p') => date_format.push_str(if self.hour < 12 { "AM" } else { "PM" }),
+                    Some('q') => date_format.push_str(self.day_period_nepali()),
+                    Some('X') => date_format.push_str(&self.time_string()),
+                    Some('c') => {
+                        date_format.push_str("%c ");
+                        date_format.push_str(&self.time_string());
+                    }
+                    Some(other) => {
+                        date_format.push('%');
+                        date_format.push(other);
+                    }
+                    None => date_format.push('%'),
+                }
+            } else {
+                date_format.push(ch);
+            }
+        }
+
+        self.date.format_date(&date_format)
+    }
+}
+
+impl fmt::Display for NepaliDateTime {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.pad(&format!("{} {}", self.date, self.time_string()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn at(hour: u8, minute: u8) -> NepaliDateTime {
+        let date = NepaliDate {
+            year: 2077,
+            month: 5,
+            day: 19,
+        };
+        NepaliDateTime::new(date, hour, minute, 0).unwrap()
+    }
+
+    #[test]
+    fn test_twelve_hour_clock() {
+        assert_eq!(at(0, 30).format("%I:%M %p"), "12:30 AM");
+        assert_eq!(at(10, 0).format("%I:%M %p"), "10:00 AM");
+        assert_eq!(at(12, 0).format("%I:%M %p"), "12:00 PM");
+        assert_eq!(at(14, 0).format("%I:%M %p"), "02:00 PM");
+        assert_eq!(at(23, 59).format("%H:%M:%S"), "23:59:00");
+    }
+
+    #[test]
+    fn test_nepali_day_periods() {
+        assert_eq!(at(0, 30).format("%q"), "राति");
+        assert_eq!(at(10, 0).format("%q"), "बिहान");
+        assert_eq!(at(14, 0).format("%q"), "दिउँसो");
+        assert_eq!(at(20, 0).format("%q"), "साँझ");
+    }
+
+    #[test]
+    fn test_date_specifiers_and_literals_pass_through() {
+        let dt = at(9, 15);
+        assert_eq!(dt.format("%Y-%m-%d %H:%M"), "2077-05-19 09:15");
+        assert_eq!(dt.format("{%H} %% %H"), "%H % 09");
+        assert_eq!(dt.to_string(), "2077-05-19 09:15:00");
+    }
+
+    #[cfg(any(feature = "lookup-tables", feature = "astronomical"))]
+    #[test]
+    fn test_presets() {
+        let dt = at(14, 5);
+        assert_eq!(dt.format("%X"), "14:05:00");
+        assert_eq!(dt.format("%x %X"), "2077-05-19 14:05:00");
+        assert_eq!(dt.format("%c"), "Shukrabaar, 19 Bhadra 2077 14:05:00");
+        assert_eq!(dt.format("%%X %%c"), "%X %c");
+    }
+
+    #[test]
+    fn test_invalid_time_rejected() {
+        let date = NepaliDate {
+            year: 2077,
+            month: 5,
+            day: 19,
+        };
+        assert!(NepaliDateTime::new(date, 24, 0, 0).is_err());
+        assert!(NepaliDateTime::new(date, 10, 60, 0).is_err());
+    }
+}
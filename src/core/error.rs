@@ -3,9 +3,14 @@
 use std::fmt;
 
 #[derive(Debug, Clone, PartialEq)]
+#[non_exhaustive]
 pub enum NpdatetimeError {
     InvalidDate(String),
     OutOfRange(String),
+    /// The date precedes the start of the BS calendar data (1975 BS / 1918-04-13 AD)
+    BeforeEpoch(String),
+    /// The date lies past the last year the enabled calendar provider covers
+    AfterSupportedRange(String),
     ParseError(String),
     CalculationError(String),
 }
@@ -15,6 +20,10 @@ impl fmt::Display for NpdatetimeError {
         match self {
             NpdatetimeError::InvalidDate(msg) => write!(f, "Invalid date: {}", msg),
             NpdatetimeError::OutOfRange(msg) => write!(f, "Out of range: {}", msg),
+            NpdatetimeError::BeforeEpoch(msg) => write!(f, "Before epoch: {}", msg),
+            NpdatetimeError::AfterSupportedRange(msg) => {
+                write!(f, "After supported range: {}", msg)
+            }
             NpdatetimeError::ParseError(msg) => write!(f, "Parse error: {}", msg),
             NpdatetimeError::CalculationError(msg) => write!(f, "Calculation error: {}", msg),
         }
@@ -0,0 +1,216 @@
+//! Festivals and national days fixed to a Bikram Sambat date
+//!
+//! Only observances tied to a solar (civil) BS month and day are listed
+//! here. Lunar festivals such as Dashain and Tihar follow the Tithi and need
+//! the astronomical layer.
+
+use crate::core::date::NepaliDate;
+use crate::core::error::Result;
+use crate::core::interval::NepaliDateInterval;
+
+/// Broad category of an observance
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum FestivalKind {
+    /// Civic and national days, such as Ganatantra Diwas
+    NationalHoliday,
+    /// Religious observances, such as Maghe Sankranti
+    Religious,
+    /// Seasonal and cultural days, such as Asare Pandra
+    Cultural,
+}
+
+/// A festival or national day on a specific date
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Festival {
+    pub date: NepaliDate,
+    /// Name in English/romanized Nepali
+    pub name: &'static str,
+    /// Name in Devanagari
+    pub name_unicode: &'static str,
+    pub kind: FestivalKind,
+}
+
+/// Observances fixed to a BS (month, day), in calendar order
+const FIXED_FESTIVALS: [(u8, u8, &str, &str, FestivalKind); 10] = [
+    (
+        1,
+        1,
+        "Nepali New Year",
+        "नयाँ वर्ष",
+        FestivalKind::NationalHoliday,
+    ),
+    (
+        1,
+        11,
+        "Loktantra Diwas",
+        "लोकतन्त्र दिवस",
+        FestivalKind::NationalHoliday,
+    ),
+    (
+        2,
+        15,
+        "Ganatantra Diwas",
+        "गणतन्त्र दिवस",
+        FestivalKind::NationalHoliday,
+    ),
+    (3, 15, "Asare Pandra", "असार पन्ध्र", FestivalKind::Cultural),
+    (
+        4,
+        1,
+        "Saune Sankranti",
+        "साउने संक्रान्ति",
+        FestivalKind::Religious,
+    ),
+    (
+        6,
+        3,
+        "Sambidhan Diwas",
+        "संविधान दिवस",
+        FestivalKind::NationalHoliday,
+    ),
+    (
+        9,
+        27,
+        "Prithvi Jayanti",
+        "पृथ्वी जयन्ती",
+        FestivalKind::NationalHoliday,
+    ),
+    (
+        10,
+        1,
+        "Maghe Sankranti",
+        "माघे संक्रान्ति",
+        FestivalKind::Religious,
+    ),
+    (
+        10,
+        16,
+        "Shahid Diwas",
+        "शहीद दिवस",
+        FestivalKind::NationalHoliday,
+    ),
+    (
+        11,
+        7,
+        "Prajatantra Diwas",
+        "प्रजातन्त्र दिवस",
+        FestivalKind::NationalHoliday,
+    ),
+];
+
+/// Returns the festivals of a BS year, sorted by date
+pub fn festivals_in_year(year: i32) -> Result<Vec<Festival>> {
+    FIXED_FESTIVALS
+        .iter()
+        .map(|&(month, day, name, name_unicode, kind)| {
+            Ok(Festival {
+                date: NepaliDate::new(year, month, day)?,
+                name,
+                name_unicode,
+                kind,
+            })
+        })
+        .collect()
+}
+
+/// Returns the festivals of one kind in a BS year, sorted by date
+///
+/// # Examples:
+/// ```
+/// use npdatetime::core::festival::{FestivalKind, festivals_of_kind};
+///
+/// # if cfg!(any(feature = "lookup-tables", feature = "astronomical")) {
+/// let holidays = festivals_of_kind(2081, FestivalKind::NationalHoliday).unwrap();
+/// assert_eq!(holidays[0].name, "Nepali New Year");
+/// # }
+/// ```
+pub fn festivals_of_kind(year: i32, kind: FestivalKind) -> Result<Vec<Festival>> {
+    let mut festivals = festivals_in_year(year)?;
+    festivals.retain(|festival| festival.kind == kind);
+    Ok(festivals)
+}
+
+/// English and Devanagari names of the fixed observance on a BS (month, day)
+#[cfg(feature = "astronomical")]
+pub(crate) fn fixed_festival_names(month: u8, day: u8) -> Option<(&'static str, &'static str)> {
+    FIXED_FESTIVALS
+        .iter()
+        .find(|&&(m, d, ..)| (m, d) == (month, day))
+        .map(|&(_, _, name, name_unicode, _)| (name, name_unicode))
+}
+
+/// Returns the festivals falling between `start` and `end` (inclusive), sorted by date
+///
+/// The range may span several years, e.g. the tail of Chaitra and the
+/// start of the next Baisakh shown together in a month view.
+pub fn festivals_between(start: NepaliDate, end: NepaliDate) -> Result<Vec<Festival>> {
+    let interval = NepaliDateInterval::new(start, end)?;
+
+    let mut festivals = Vec::new();
+    for year in start.year..=end.year {
+        festivals.extend(
+            festivals_in_year(year)?
+                .into_iter()
+                .filter(|festival| interval.contains(&festival.date)),
+        );
+    }
+    Ok(festivals)
+}
+
+#[cfg(test)]
+#[cfg(any(feature = "lookup-tables", feature = "astronomical"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_festivals_in_year_sorted() {
+        let festivals = festivals_in_year(2081).unwrap();
+        assert_eq!(festivals.len(), FIXED_FESTIVALS.len());
+        assert!(festivals.windows(2).all(|w| w[0].date < w[1].date));
+        assert_eq!(festivals[0].name, "Nepali New Year");
+    }
+
+    #[test]
+    fn test_festivals_of_kind() {
+        let holidays = festivals_of_kind(2081, FestivalKind::NationalHoliday).unwrap();
+        assert_eq!(holidays.len(), 7);
+        assert!(
+            holidays
+                .iter()
+                .all(|f| f.kind == FestivalKind::NationalHoliday)
+        );
+        assert!(!holidays.iter().any(|f| f.name == "Maghe Sankranti"));
+
+        let total: usize = [
+            FestivalKind::NationalHoliday,
+            FestivalKind::Religious,
+            FestivalKind::Cultural,
+        ]
+        .into_iter()
+        .map(|kind| festivals_of_kind(2081, kind).unwrap().len())
+        .sum();
+        assert_eq!(total, FIXED_FESTIVALS.len());
+    }
+
+    #[test]
+    fn test_festivals_between_across_new_year() {
+        let start = NepaliDate::new(2080, 11, 1).unwrap();
+        let end = NepaliDate::new(2081, 1, 15).unwrap();
+        let festivals = festivals_between(start, end).unwrap();
+
+        let found: Vec<_> = festivals.iter().map(|f| (f.date, f.name)).collect();
+        assert_eq!(
+            found,
+            vec![
+                (NepaliDate::new(2080, 11, 7).unwrap(), "Prajatantra Diwas"),
+                (NepaliDate::new(2081, 1, 1).unwrap(), "Nepali New Year"),
+                (NepaliDate::new(2081, 1, 11).unwrap(), "Loktantra Diwas"),
+            ]
+        );
+
+        // Endpoints are inclusive
+        let day = NepaliDate::new(2081, 1, 11).unwrap();
+        assert_eq!(festivals_between(day, day).unwrap().len(), 1);
+        assert!(festivals_between(end, start).is_err());
+    }
+}
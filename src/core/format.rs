@@ -4,8 +4,20 @@
 //! weekdays, and custom formatting patterns.
 
 use crate::core::date::{NEPALI_MONTHS, NEPALI_MONTHS_UNICODE, NEPALI_WEEKDAYS, NepaliDate};
+use crate::core::error::Result;
+use std::fmt;
 
 impl NepaliDate {
+    /// Abbreviated month name, e.g. "Bha" for Bhadra
+    pub fn month_name_short(&self) -> String {
+        abbreviate(NEPALI_MONTHS[(self.month - 1) as usize])
+    }
+
+    /// Abbreviated romanized weekday name, e.g. "Shu" for Shukrabaar
+    pub fn day_name_short(&self) -> Result<String> {
+        Ok(abbreviate(self.weekday()?.nepali_name()))
+    }
+
     /// Formats the date using a format string
     ///
     /// # Format Specifiers:
@@ -16,13 +28,31 @@ impl NepaliDate {
     /// - `%b` - Abbreviated month name (first 3 letters)
     /// - `%d` - Day as zero-padded decimal (01-31)
     /// - `%e` - Day as space-padded decimal ( 1-31)
+    /// - `%j` - Day of the year as zero-padded decimal (001-366)
     /// - `%A` - Full weekday name (requires conversion to Gregorian)
+    /// - `%a` - Abbreviated weekday name (first 3 letters)
     /// - `%K` - Devanagari year (e.g., २०७७)
     /// - `%n` - Devanagari month (e.g., ०५)
     /// - `%D` - Devanagari day (e.g., १९)
     /// - `%N` - Devanagari month name (e.g., भाद्र)
     /// - `%G` - Devanagari weekday name (e.g., शुक्रवार)
+    /// - `%U` - Week of the year, Sunday first (00-53); days before the first
+    ///   Sunday of Baisakh are in week 00
+    /// - `%W` - Week of the year, Monday first (00-53)
+    /// - `%F` - Fiscal year, starting on Shrawan 1 (e.g., 2077/78)
+    /// - `%P` - Bikram Sambat era label in English (`B.S.`)
+    /// - `%V` - Bikram Sambat era label in Devanagari (`वि.सं.`)
+    /// - `%x` - Preset date, same as `%Y-%m-%d` (2077-05-19)
+    /// - `%c` - Preset full date, same as `%A, %d %B %Y`
+    ///   (Shukrabaar, 19 Bhadra 2077)
     /// - `%%` - Literal % character
+    /// - `{...}` - Literal block; the text between the braces is copied
+    ///   verbatim (without the braces), so `"{Day:} %d"` prints `Day: 19`.
+    ///   A `{` without a closing `}` is printed as-is.
+    ///
+    /// `%V` is not the ISO week number as in C `strftime`. `%E` (Gregorian
+    /// year) is only expanded by [`NepaliDate::format_with_ad`]; here it is
+    /// left untouched like any other unknown specifier.
     ///
     /// # Examples:
     /// ```
@@ -34,63 +64,53 @@ impl NepaliDate {
     /// # }
     /// ```
     pub fn format_date(&self, format_str: &str) -> String {
-        let mut result = String::new();
-        let mut chars = format_str.chars().peekable();
-
-        while let Some(ch) = chars.next() {
-            if ch == '%' {
-                if let Some(&next_ch) = chars.peek() {
-                    chars.next(); // consume the format character
-                    match next_ch {
-                        'Y' => result.push_str(&self.year.to_string()),
-                        'y' => result.push_str(&format!("{:02}", self.year % 100)),
-                        'm' => result.push_str(&format!("{:02}", self.month)),
-                        'B' => result.push_str(NEPALI_MONTHS[(self.month - 1) as usize]),
-                        'b' => result.push_str(&NEPALI_MONTHS[(self.month - 1) as usize][..3]),
-                        'd' => result.push_str(&format!("{:02}", self.day)),
-                        'e' => result.push_str(&format!("{:2}", self.day)),
-                        'A' => {
-                            // Calculate weekday (requires conversion to Gregorian)
-                            if let Ok((y, m, d)) = self.to_gregorian() {
-                                let weekday = calculate_weekday(y, m, d);
-                                result.push_str(NEPALI_WEEKDAYS[weekday]);
-                            }
-                        }
-                        'K' => result.push_str(&to_devanagari_number(self.year)),
-                        'n' => result.push_str(&to_devanagari_number_padded(self.month as i32, 2)),
-                        'D' => result.push_str(&to_devanagari_number_padded(self.day as i32, 2)),
-                        'N' => result.push_str(NEPALI_MONTHS_UNICODE[(self.month - 1) as usize]),
-                        'G' => {
-                            if let Ok((y, m, d)) = self.to_gregorian() {
-                                let weekday = calculate_weekday(y, m, d);
-                                const DEVANAGARI_WEEKDAYS: [&str; 7] = [
-                                    "आइतवार",
-                                    "सोमवार",
-                                    "मङ्गलवार",
-                                    "बुधवार",
-                                    "बिहीवार",
-                                    "शुक्रवार",
-                                    "शनिवार",
-                                ];
-                                result.push_str(DEVANAGARI_WEEKDAYS[weekday]);
-                            }
-                        }
-                        '%' => result.push('%'),
-                        _ => {
-                            // Unknown format specifier - keep as-is
-                            result.push('%');
-                            result.push(next_ch);
-                        }
-                    }
-                } else {
-                    result.push('%');
-                }
-            } else {
-                result.push(ch);
-            }
-        }
+        self.format_with_era(format_str, None)
+    }
 
-        result
+    /// Formats the date like [`NepaliDate::format_date`], additionally
+    /// expanding `%E` to the corresponding Gregorian (AD) year.
+    ///
+    /// This is useful for bilingual documents that print both eras side by
+    /// side. Fails if the date cannot be converted to Gregorian.
+    ///
+    /// # Examples:
+    /// ```
+    /// # use npdatetime::NepaliDate;
+    /// # if cfg!(any(feature = "lookup-tables", feature = "astronomical")) {
+    /// let date = NepaliDate::new(2077, 5, 19).unwrap();
+    /// assert_eq!(
+    ///     date.format_with_ad("%K %V (%E A.D.)").unwrap(),
+    ///     "२०७७ वि.सं. (2020 A.D.)"
+    /// );
+    /// # }
+    /// ```
+    pub fn format_with_ad(&self, format_str: &str) -> Result<String> {
+        let (ad_year, _, _) = self.to_gregorian()?;
+        Ok(self.format_with_era(format_str, Some(ad_year)))
+    }
+
+    /// Writes the date formatted like [`NepaliDate::format_date`] into `w`
+    ///
+    /// Lets hot paths reuse one buffer, or write straight into a
+    /// [`fmt::Formatter`], instead of allocating a `String` per call.
+    ///
+    /// # Examples:
+    /// ```
+    /// # use npdatetime::NepaliDate;
+    /// # if cfg!(any(feature = "lookup-tables", feature = "astronomical")) {
+    /// let mut line = String::new();
+    /// let date = NepaliDate::new(2077, 5, 19).unwrap();
+    /// date.format_into(&mut line, "[%Y-%m-%d] ").unwrap();
+    /// line.push_str("backup finished");
+    /// assert_eq!(line, "[2077-05-19] backup finished");
+    /// # }
+    /// ```
+    pub fn format_into<W: fmt::Write>(&self, w: &mut W, format_str: &str) -> fmt::Result {
+        write_fields(w, self, format_str, None)
+    }
+
+    fn format_with_era(&self, format_str: &str, ad_year: Option<i32>) -> String {
+        format_fields(self, format_str, ad_year)
     }
 
     /// Formats the date in Unicode Devanagari script
@@ -113,38 +133,273 @@ impl NepaliDate {
     }
 
     /// Generates a visual calendar string for the month of this date
+    ///
+    /// # Panics
+    /// If the month is outside the supported range; see
+    /// [`NepaliDate::try_month_calendar`] for a fallible version.
     pub fn month_calendar(&self) -> String {
+        self.month_calendar_with(WeekStart::Sunday)
+    }
+
+    /// Generates a visual calendar string with weeks starting on `week_start`
+    ///
+    /// # Panics
+    /// If the month is outside the supported range; see
+    /// [`NepaliDate::try_month_calendar_with`] for a fallible version.
+    ///
+    /// # Example:
+    /// ```
+    /// # use npdatetime::NepaliDate;
+    /// # use npdatetime::core::format::WeekStart;
+    /// # if cfg!(any(feature = "lookup-tables", feature = "astronomical")) {
+    /// let date = NepaliDate::new(2077, 1, 1).unwrap();
+    /// let cal = date.month_calendar_with(WeekStart::Monday);
+    /// assert_eq!(cal.lines().nth(1), Some("Mo Tu We Th Fr Sa Su"));
+    /// # }
+    /// ```
+    pub fn month_calendar_with(&self, week_start: WeekStart) -> String {
+        self.try_month_calendar_with(week_start)
+            .expect("month_calendar requires a date within the supported range")
+    }
+
+    /// Generates a visual calendar string for the month of this date, or an
+    /// error if the month cannot be converted
+    pub fn try_month_calendar(&self) -> Result<String> {
+        self.try_month_calendar_with(WeekStart::Sunday)
+    }
+
+    /// Generates a visual calendar string with weeks starting on `week_start`,
+    /// or an error if the month cannot be converted
+    ///
+    /// The first weekday and the month length both come from the calendar
+    /// data, so a failure is reported instead of drawing a misaligned grid.
+    pub fn try_month_calendar_with(&self, week_start: WeekStart) -> Result<String> {
+        const WEEKDAY_LABELS: [&str; 7] = ["Su", "Mo", "Tu", "We", "Th", "Fr", "Sa"];
+
+        let first_day = NepaliDate::new(self.year, self.month, 1)?;
+        let (g_y, g_m, g_d) = first_day.to_gregorian()?;
+        let days = Self::days_in_month(self.year, self.month)?;
+
         let mut result = String::new();
         let month_name = NEPALI_MONTHS[(self.month - 1) as usize];
         let header = format!("{} {}", month_name, self.year);
         result.push_str(&format!("{:^20}\n", header));
-        result.push_str("Su Mo Tu We Th Fr Sa\n");
 
-        let first_day = NepaliDate::new(self.year, self.month, 1).unwrap();
-        let (g_y, g_m, g_d) = first_day.to_gregorian().unwrap_or((1943, 4, 14));
+        let first = week_start.weekday_index();
+        let labels: Vec<&str> = (0..7).map(|i| WEEKDAY_LABELS[(first + i) % 7]).collect();
+        result.push_str(&labels.join(" "));
+        result.push('\n');
+
         let start_weekday = calculate_weekday(g_y, g_m, g_d);
+        let offset = (start_weekday + 7 - first) % 7;
 
-        for _ in 0..start_weekday {
+        for _ in 0..offset {
             result.push_str("   ");
         }
 
-        let days = Self::days_in_month(self.year, self.month).unwrap_or(30);
         for day in 1..=days {
             result.push_str(&format!("{:2} ", day));
-            if (day as usize + start_weekday).is_multiple_of(7) {
+            if (day as usize + offset).is_multiple_of(7) {
                 result.push('\n');
             }
         }
-        if !(days as usize + start_weekday).is_multiple_of(7) {
+        if !(days as usize + offset).is_multiple_of(7) {
             result.push('\n');
         }
 
-        result
+        Ok(result)
+    }
+}
+
+/// Values the format engine needs beyond the year, month and day, which
+/// depend on the calendar (civil lookup or astronomical) behind the date
+pub(crate) trait FormatSource {
+    /// Year, month and day as displayed
+    fn ymd(&self) -> (i32, u8, u8);
+
+    /// Weekday index (0 = Sunday) for `%A`, `%a` and `%G`
+    fn weekday_index(&self) -> Option<usize>;
+
+    /// Day of the year (1 for Baisakh 1) and the weekday index of Baisakh 1,
+    /// for `%j`, `%U` and `%W`
+    fn year_position(&self) -> Option<(u16, usize)>;
+}
+
+/// Expansion of `%x`
+pub(crate) const PRESET_DATE: &str = "%Y-%m-%d";
+
+/// Expansion of `%c` for dates
+pub(crate) const PRESET_FULL: &str = "%A, %d %B %Y";
+
+/// Expands the specifiers documented on [`NepaliDate::format_date`]
+///
+/// `%E` prints `ad_year` when given and is kept as-is otherwise. Specifiers
+/// whose value `source` cannot provide expand to nothing.
+pub(crate) fn format_fields(
+    source: &impl FormatSource,
+    format_str: &str,
+    ad_year: Option<i32>,
+) -> String {
+    let mut result = String::new();
+    // Writing into a String cannot fail
+    let _ = write_fields(&mut result, source, format_str, ad_year);
+    result
+}
+
+/// Streaming form of [`format_fields`], writing the expansion into `w`
+pub(crate) fn write_fields<W: fmt::Write>(
+    w: &mut W,
+    source: &impl FormatSource,
+    format_str: &str,
+    ad_year: Option<i32>,
+) -> fmt::Result {
+    let (year, month, day) = source.ymd();
+    let mut chars = format_str.chars().peekable();
+
+    while let Some(ch) = chars.next() {
+        if ch == '{' {
+            // Copy a `{...}` block verbatim, skipping past its closing brace
+            let literal: String = chars.clone().take_while(|&c| c != '}').collect();
+            let len = literal.chars().count();
+            if chars.clone().nth(len) == Some('}') {
+                w.write_str(&literal)?;
+                chars.nth(len);
+            } else {
+                w.write_char(ch)?;
+            }
+        } else if ch == '%' {
+            if let Some(&next_ch) = chars.peek() {
+                chars.next(); // consume the format character
+                match next_ch {
+                    'Y' => write!(w, "{}", year)?,
+                    'y' => write!(w, "{:02}", year % 100)?,
+                    'm' => write!(w, "{:02}", month)?,
+                    'B' => w.write_str(NEPALI_MONTHS[(month - 1) as usize])?,
+                    'b' => w.write_str(&abbreviate(NEPALI_MONTHS[(month - 1) as usize]))?,
+                    'd' => write!(w, "{:02}", day)?,
+                    'e' => write!(w, "{:2}", day)?,
+                    'A' => {
+                        if let Some(weekday) = source.weekday_index() {
+                            w.write_str(NEPALI_WEEKDAYS[weekday])?;
+                        }
+                    }
+                    'a' => {
+                        if let Some(weekday) = source.weekday_index() {
+                            w.write_str(&abbreviate(NEPALI_WEEKDAYS[weekday]))?;
+                        }
+                    }
+                    'K' => w.write_str(&to_devanagari_number(year))?,
+                    'n' => w.write_str(&to_devanagari_number_padded(month as i32, 2))?,
+                    'D' => w.write_str(&to_devanagari_number_padded(day as i32, 2))?,
+                    'N' => w.write_str(NEPALI_MONTHS_UNICODE[(month - 1) as usize])?,
+                    'G' => {
+                        if let Some(weekday) = source.weekday_index() {
+                            const DEVANAGARI_WEEKDAYS: [&str; 7] = [
+                                "आइतवार",
+                                "सोमवार",
+                                "मङ्गलवार",
+                                "बुधवार",
+                                "बिहीवार",
+                                "शुक्रवार",
+                                "शनिवार",
+                            ];
+                            w.write_str(DEVANAGARI_WEEKDAYS[weekday])?;
+                        }
+                    }
+                    'j' => {
+                        if let Some((yday, _)) = source.year_position() {
+                            write!(w, "{:03}", yday)?;
+                        }
+                    }
+                    'U' | 'W' => {
+                        if let Some((yday, first)) = source.year_position() {
+                            let week = strftime_week(yday, first, next_ch == 'W');
+                            write!(w, "{:02}", week)?;
+                        }
+                    }
+                    'x' => write_fields(w, source, PRESET_DATE, ad_year)?,
+                    'c' => write_fields(w, source, PRESET_FULL, ad_year)?,
+                    'F' => w.write_str(&NepaliDate { year, month, day }.fiscal_year())?,
+                    'P' => w.write_str("B.S.")?,
+                    'V' => w.write_str("वि.सं.")?,
+                    'E' => match ad_year {
+                        Some(y) => write!(w, "{}", y)?,
+                        None => w.write_str("%E")?,
+                    },
+                    '%' => w.write_char('%')?,
+                    _ => {
+                        // Unknown format specifier - keep as-is
+                        w.write_char('%')?;
+                        w.write_char(next_ch)?;
+                    }
+                }
+            } else {
+                w.write_char('%')?;
+            }
+        } else {
+            w.write_char(ch)?;
+        }
     }
+
+    Ok(())
+}
+
+/// C `strftime`-style week number counted from the first Sunday (or Monday)
+fn strftime_week(day_of_year: u16, first_weekday: usize, monday_first: bool) -> u16 {
+    let yday = day_of_year - 1;
+    let mut weekday = (first_weekday as u16 + yday) % 7;
+    if monday_first {
+        weekday = (weekday + 6) % 7;
+    }
+    (yday + 7 - weekday) / 7
+}
+
+impl FormatSource for NepaliDate {
+    fn ymd(&self) -> (i32, u8, u8) {
+        (self.year, self.month, self.day)
+    }
+
+    fn weekday_index(&self) -> Option<usize> {
+        self.weekday().ok().map(|w| w.index() as usize)
+    }
+
+    fn year_position(&self) -> Option<(u16, usize)> {
+        let first = Self::year_start_weekday(self.year).ok()?;
+        Some((self.day_of_year().ok()?, first.index() as usize))
+    }
+}
+
+/// First day of the week used when rendering calendar grids
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum WeekStart {
+    /// Weeks run Sunday to Saturday (the common Nepali wall-calendar layout)
+    #[default]
+    Sunday,
+    Monday,
+    Saturday,
+}
+
+impl WeekStart {
+    /// Weekday index of the first column (0 = Sunday, 6 = Saturday)
+    fn weekday_index(self) -> usize {
+        match self {
+            WeekStart::Sunday => 0,
+            WeekStart::Monday => 1,
+            WeekStart::Saturday => 6,
+        }
+    }
+}
+
+/// First three characters of a name
+///
+/// Cuts at `char` boundaries rather than bytes, so Devanagari names can be
+/// abbreviated without panicking.
+pub(crate) fn abbreviate(name: &str) -> String {
+    name.chars().take(3).collect()
 }
 
 /// Calculate weekday using Zeller's congruence (0 = Sunday, 6 = Saturday)
-fn calculate_weekday(year: i32, month: u8, day: u8) -> usize {
+pub(crate) fn calculate_weekday(year: i32, month: u8, day: u8) -> usize {
     let mut y = year;
     let mut m = month as i32;
 
@@ -164,12 +419,12 @@ fn calculate_weekday(year: i32, month: u8, day: u8) -> usize {
     ((h + 6) % 7) as usize
 }
 
-/// Convert a number to Devanagari numerals
-fn to_devanagari_number(num: i32) -> String {
+/// Replace the ASCII digits of `s` with Devanagari numerals, keeping any
+/// other characters
+pub(crate) fn to_devanagari_digits(s: &str) -> String {
     const DEVANAGARI_DIGITS: [char; 10] = ['०', '१', '२', '३', '४', '५', '६', '७', '८', '९'];
 
-    num.to_string()
-        .chars()
+    s.chars()
         .map(|c| {
             if let Some(digit) = c.to_digit(10) {
                 DEVANAGARI_DIGITS[digit as usize]
@@ -180,20 +435,14 @@ fn to_devanagari_number(num: i32) -> String {
         .collect()
 }
 
+/// Convert a number to Devanagari numerals
+fn to_devanagari_number(num: i32) -> String {
+    to_devanagari_digits(&num.to_string())
+}
+
 /// Convert a number to Devanagari numerals with padding
 fn to_devanagari_number_padded(num: i32, width: usize) -> String {
-    let s = format!("{:0width$}", num, width = width);
-    const DEVANAGARI_DIGITS: [char; 10] = ['०', '१', '२', '३', '४', '५', '६', '७', '८', '९'];
-
-    s.chars()
-        .map(|c| {
-            if let Some(digit) = c.to_digit(10) {
-                DEVANAGARI_DIGITS[digit as usize]
-            } else {
-                c
-            }
-        })
-        .collect()
+    to_devanagari_digits(&format!("{:0width$}", num, width = width))
 }
 
 #[cfg(test)]
@@ -217,6 +466,25 @@ mod tests {
         assert_eq!(date.format_date("%b"), "Bha");
     }
 
+    #[test]
+    fn test_abbreviate_is_char_safe() {
+        let short = abbreviate(NEPALI_MONTHS_UNICODE[4]);
+        assert_eq!(short.chars().count(), 3);
+        assert!(NEPALI_MONTHS_UNICODE[4].starts_with(&short));
+        assert_eq!(abbreviate("Bhadra"), "Bha");
+        assert_eq!(abbreviate("ab"), "ab");
+    }
+
+    #[cfg(any(feature = "lookup-tables", feature = "astronomical"))]
+    #[test]
+    fn test_short_names() {
+        // 2077-05-19 is a Friday (Shukrabaar)
+        let date = NepaliDate::new(2077, 5, 19).unwrap();
+        assert_eq!(date.month_name_short(), "Bha");
+        assert_eq!(date.day_name_short().unwrap(), "Shu");
+        assert_eq!(date.format_date("%a %d %b"), "Shu 19 Bha");
+    }
+
     #[cfg(any(feature = "lookup-tables", feature = "astronomical"))]
     #[test]
     fn test_format_day() {
@@ -233,6 +501,144 @@ mod tests {
         assert_eq!(date.format_date("%d %B %Y"), "19 Bhadra 2077");
     }
 
+    #[cfg(any(feature = "lookup-tables", feature = "astronomical"))]
+    #[test]
+    fn test_format_era_labels() {
+        let date = NepaliDate::new(2077, 5, 19).unwrap();
+        assert_eq!(date.format_date("%Y %P"), "2077 B.S.");
+        assert_eq!(date.format_date("%K %V"), "२०७७ वि.सं.");
+        // %E needs the Gregorian conversion and is left as-is here
+        assert_eq!(date.format_date("%E"), "%E");
+    }
+
+    #[test]
+    fn test_format_fiscal_year() {
+        let shrawan = NepaliDate {
+            year: 2080,
+            month: 4,
+            day: 1,
+        };
+        let ashadh = NepaliDate {
+            year: 2080,
+            month: 3,
+            day: 31,
+        };
+        assert_eq!(shrawan.format_date("FY %F"), "FY 2080/81");
+        assert_eq!(ashadh.format_date("FY %F"), "FY 2079/80");
+        assert_eq!(shrawan.format_date("%%F {%F}"), "%F %F");
+    }
+
+    #[cfg(any(feature = "lookup-tables", feature = "astronomical"))]
+    #[test]
+    fn test_format_with_ad_mixed_era() {
+        let date = NepaliDate::new(2077, 5, 19).unwrap();
+        assert_eq!(
+            date.format_with_ad("%K %V / %E ई.सं.").unwrap(),
+            "२०७७ वि.सं. / 2020 ई.सं."
+        );
+        assert_eq!(
+            date.format_with_ad("%Y %P (%E A.D.)").unwrap(),
+            "2077 B.S. (2020 A.D.)"
+        );
+    }
+
+    #[cfg(any(feature = "lookup-tables", feature = "astronomical"))]
+    #[test]
+    fn test_format_week_numbers() {
+        // 2081-01-01 BS is a Saturday
+        let sat = NepaliDate::new(2081, 1, 1).unwrap();
+        let sun = NepaliDate::new(2081, 1, 2).unwrap();
+        let mon = NepaliDate::new(2081, 1, 3).unwrap();
+        assert_eq!(sat.format_date("%U %W"), "00 00");
+        assert_eq!(sun.format_date("%U %W"), "01 00");
+        assert_eq!(mon.format_date("%U %W"), "01 01");
+    }
+
+    #[cfg(any(feature = "lookup-tables", feature = "astronomical"))]
+    #[test]
+    fn test_format_literal_blocks() {
+        let date = NepaliDate::new(2077, 5, 9).unwrap();
+        assert_eq!(date.format_date("{Day} %d"), "Day 09");
+        assert_eq!(date.format_date("{100% %d} %d"), "100% %d 09");
+        assert_eq!(date.format_date("{मिति:} %D %N"), "मिति: ०९ भाद्र");
+        assert_eq!(date.format_date("%%{%%}"), "%%%");
+        assert_eq!(date.format_date("{open %d"), "{open 09");
+    }
+
+    #[cfg(any(feature = "lookup-tables", feature = "astronomical"))]
+    #[test]
+    fn test_format_into_reused_buffer() {
+        let mut buffer = String::new();
+        for (y, m, d) in [(2077, 5, 19), (2081, 1, 1), (2081, 12, 31)] {
+            let date = NepaliDate::new(y, m, d).unwrap();
+            for fmt in ["%x", "%c", "%D %N %K {%d}", "%U/%W %P"] {
+                buffer.clear();
+                date.format_into(&mut buffer, fmt).unwrap();
+                assert_eq!(buffer, date.format_date(fmt));
+            }
+        }
+    }
+
+    #[cfg(any(feature = "lookup-tables", feature = "astronomical"))]
+    #[test]
+    fn test_format_presets() {
+        let date = NepaliDate::new(2077, 5, 19).unwrap();
+        assert_eq!(date.format_date("%x"), date.format_date(PRESET_DATE));
+        assert_eq!(date.format_date("%x"), "2077-05-19");
+        assert_eq!(date.format_date("%Y-%j"), "2077-145");
+        assert_eq!(date.format_date("%c"), "Shukrabaar, 19 Bhadra 2077");
+        assert_eq!(date.format_date("%%x %x%%"), "%x 2077-05-19%");
+        assert_eq!(date.format_date("{%c} %c"), "%c Shukrabaar, 19 Bhadra 2077");
+    }
+
+    #[cfg(any(feature = "lookup-tables", feature = "astronomical"))]
+    #[test]
+    fn test_month_calendar_week_start() {
+        // 2077 Baisakh 1 is Monday, 2020-04-13
+        let date = NepaliDate::new(2077, 1, 15).unwrap();
+        let cases = [
+            (WeekStart::Sunday, "Su Mo Tu We Th Fr Sa", 1),
+            (WeekStart::Monday, "Mo Tu We Th Fr Sa Su", 0),
+            (WeekStart::Saturday, "Sa Su Mo Tu We Th Fr", 2),
+        ];
+
+        for (week_start, header, offset) in cases {
+            let cal = date.month_calendar_with(week_start);
+            let lines: Vec<&str> = cal.lines().collect();
+            assert_eq!(lines[1], header);
+            let first_week = format!("{}{}", "   ".repeat(offset), " 1 ");
+            assert!(lines[2].starts_with(&first_week), "{:?}", lines[2]);
+        }
+
+        assert_eq!(
+            date.month_calendar(),
+            date.month_calendar_with(WeekStart::Sunday)
+        );
+    }
+
+    #[test]
+    fn test_try_month_calendar() {
+        if cfg!(any(feature = "lookup-tables", feature = "astronomical")) {
+            let date = NepaliDate::new(2077, 5, 19).unwrap();
+            assert_eq!(date.try_month_calendar().unwrap(), date.month_calendar());
+        }
+
+        // Only the lookup table has a hard upper bound
+        if cfg!(not(feature = "astronomical")) {
+            let out_of_range = NepaliDate {
+                year: 2200,
+                month: 1,
+                day: 1,
+            };
+            assert!(out_of_range.try_month_calendar().is_err());
+            assert!(
+                out_of_range
+                    .try_month_calendar_with(WeekStart::Monday)
+                    .is_err()
+            );
+        }
+    }
+
     #[test]
     fn test_devanagari_numbers() {
         assert_eq!(to_devanagari_number(2077), "२०७७");
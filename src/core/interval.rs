@@ -0,0 +1,134 @@
+//! Closed intervals of Nepali dates
+
+use crate::core::date::NepaliDate;
+use crate::core::error::{NpdatetimeError, Result};
+use std::fmt;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// An inclusive range of Nepali dates, e.g. a reporting window
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct NepaliDateInterval {
+    pub start: NepaliDate,
+    pub end: NepaliDate,
+}
+
+impl NepaliDateInterval {
+    /// Creates an interval covering `start..=end`
+    pub fn new(start: NepaliDate, end: NepaliDate) -> Result<Self> {
+        if start > end {
+            return Err(NpdatetimeError::InvalidDate(format!(
+                "Interval start {} is after end {}",
+                start, end
+            )));
+        }
+
+        Ok(NepaliDateInterval { start, end })
+    }
+
+    /// Creates the interval covering a whole BS month
+    ///
+    /// # Examples:
+    /// ```
+    /// # use npdatetime::{NepaliDate, NepaliDateInterval};
+    /// # if cfg!(any(feature = "lookup-tables", feature = "astronomical")) {
+    /// let bhadra = NepaliDateInterval::from_month(2077, 5).unwrap();
+    /// assert!(bhadra.contains(&NepaliDate::new(2077, 5, 19).unwrap()));
+    /// assert_eq!(bhadra.to_string(), "2077-05-01..=2077-05-31");
+    /// # }
+    /// ```
+    pub fn from_month(year: i32, month: u8) -> Result<Self> {
+        let last_day = NepaliDate::days_in_month(year, month)?;
+        Ok(NepaliDateInterval {
+            start: NepaliDate::new(year, month, 1)?,
+            end: NepaliDate::new(year, month, last_day)?,
+        })
+    }
+
+    /// Creates the interval covering a whole BS year, Baisakh 1 to the last
+    /// day of Chaitra
+    pub fn from_year(year: i32) -> Result<Self> {
+        let last_day = NepaliDate::days_in_month(year, 12)?;
+        Ok(NepaliDateInterval {
+            start: NepaliDate::new(year, 1, 1)?,
+            end: NepaliDate::new(year, 12, last_day)?,
+        })
+    }
+
+    /// Returns true if the date lies within the interval (both ends inclusive)
+    pub fn contains(&self, date: &NepaliDate) -> bool {
+        self.start <= *date && *date <= self.end
+    }
+
+    /// Returns true if the Gregorian date falls within the interval
+    ///
+    /// The Gregorian date is converted to BS once and then compared.
+    pub fn contains_gregorian(&self, year: i32, month: u8, day: u8) -> Result<bool> {
+        let date = NepaliDate::from_gregorian(year, month, day)?;
+        Ok(self.contains(&date))
+    }
+}
+
+impl fmt::Display for NepaliDateInterval {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}..={}", self.start, self.end)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_reversed_interval_rejected() {
+        let a = NepaliDate {
+            year: 2080,
+            month: 1,
+            day: 1,
+        };
+        let b = NepaliDate {
+            year: 2080,
+            month: 12,
+            day: 30,
+        };
+        assert!(NepaliDateInterval::new(b, a).is_err());
+        assert!(NepaliDateInterval::new(a, a).is_ok());
+    }
+
+    #[cfg(any(feature = "lookup-tables", feature = "astronomical"))]
+    #[test]
+    fn test_from_month_and_year_bounds() {
+        let bhadra = NepaliDateInterval::from_month(2077, 5).unwrap();
+        assert_eq!(bhadra.start, NepaliDate::new(2077, 5, 1).unwrap());
+        assert_eq!(bhadra.end, NepaliDate::new(2077, 5, 31).unwrap());
+        assert_eq!(bhadra.end.add_days(1).unwrap().day, 1);
+
+        let year = NepaliDateInterval::from_year(2081).unwrap();
+        assert_eq!(year.start, NepaliDate::new(2081, 1, 1).unwrap());
+        assert_eq!(year.end, NepaliDate::new(2081, 12, 31).unwrap());
+        assert_eq!(
+            year.start.days_between(&year.end).unwrap() + 1,
+            NepaliDate::days_in_year(2081).unwrap() as i64
+        );
+
+        assert!(NepaliDateInterval::from_month(2077, 13).is_err());
+    }
+
+    #[cfg(any(feature = "lookup-tables", feature = "astronomical"))]
+    #[test]
+    fn test_contains_gregorian_edges() {
+        // 2080-04-01 BS is 2023-07-17 AD, 2080-06-30 BS is 2023-10-17 AD
+        let interval = NepaliDateInterval::new(
+            NepaliDate::new(2080, 4, 1).unwrap(),
+            NepaliDate::new(2080, 6, 30).unwrap(),
+        )
+        .unwrap();
+
+        assert!(!interval.contains_gregorian(2023, 7, 16).unwrap());
+        assert!(interval.contains_gregorian(2023, 7, 17).unwrap());
+        assert!(interval.contains_gregorian(2023, 10, 17).unwrap());
+        assert!(!interval.contains_gregorian(2023, 10, 18).unwrap());
+    }
+}
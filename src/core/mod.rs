@@ -3,10 +3,32 @@
 //! Provides the primary date and datetime structures used across both
 //! lookup-based and astronomical calculation methods.
 
+pub mod builder;
+pub mod convert;
 pub mod date;
+pub mod datetime;
 pub mod error;
+pub mod festival;
 pub mod format;
+pub mod interval;
+pub mod month;
 pub mod parse;
+pub mod range;
+#[cfg(feature = "serde")]
+pub mod serde_format;
+#[cfg(feature = "time")]
+pub mod time_support;
+pub mod weekday;
+pub mod year;
 
+pub use builder::NepaliDateBuilder;
 pub use date::NepaliDate;
+pub use datetime::NepaliDateTime;
 pub use error::{NpdatetimeError, Result};
+pub use festival::{Festival, FestivalKind};
+pub use format::WeekStart;
+pub use interval::NepaliDateInterval;
+pub use month::NepaliMonth;
+pub use range::{NepaliDateRange, NepaliDateStep};
+pub use weekday::Weekday;
+pub use year::DayInfo;
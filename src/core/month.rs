@@ -0,0 +1,197 @@
+//! Months of the Bikram Sambat year
+
+use crate::core::date::{NEPALI_MONTHS, NEPALI_MONTHS_UNICODE, NepaliDate};
+use crate::core::error::Result;
+use crate::core::parse::MONTH_ALIASES;
+use std::fmt;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Month of the BS year, numbered from Baisakh (1) to Chaitra (12)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum NepaliMonth {
+    Baisakh,
+    Jestha,
+    Ashadh,
+    Shrawan,
+    Bhadra,
+    Ashwin,
+    Kartik,
+    Mangsir,
+    Poush,
+    Magh,
+    Falgun,
+    Chaitra,
+}
+
+impl NepaliMonth {
+    /// All months in order, starting with Baisakh
+    pub const ALL: [NepaliMonth; 12] = [
+        NepaliMonth::Baisakh,
+        NepaliMonth::Jestha,
+        NepaliMonth::Ashadh,
+        NepaliMonth::Shrawan,
+        NepaliMonth::Bhadra,
+        NepaliMonth::Ashwin,
+        NepaliMonth::Kartik,
+        NepaliMonth::Mangsir,
+        NepaliMonth::Poush,
+        NepaliMonth::Magh,
+        NepaliMonth::Falgun,
+        NepaliMonth::Chaitra,
+    ];
+
+    /// Creates a month from its number (1 = Baisakh, 12 = Chaitra)
+    pub fn from_number(month: u8) -> Option<Self> {
+        Self::ALL.get((month as usize).wrapping_sub(1)).copied()
+    }
+
+    /// Returns the month number (1 = Baisakh, 12 = Chaitra)
+    pub fn number(self) -> u8 {
+        self as u8 + 1
+    }
+
+    /// Romanized name of the month (e.g. "Baisakh")
+    pub fn name(self) -> &'static str {
+        NEPALI_MONTHS[self as usize]
+    }
+
+    /// Devanagari name of the month (e.g. "बैशाख")
+    pub fn unicode_name(self) -> &'static str {
+        NEPALI_MONTHS_UNICODE[self as usize]
+    }
+}
+
+impl fmt::Display for NepaliMonth {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.pad(self.name())
+    }
+}
+
+/// Returns the month number (1-12) for a month name
+///
+/// Matching ignores ASCII case and surrounding whitespace, and accepts the
+/// canonical romanized names, the alternate spellings in [`MONTH_ALIASES`]
+/// and the Devanagari names.
+///
+/// # Examples:
+/// ```
+/// use npdatetime::core::month::month_index_from_name;
+///
+/// assert_eq!(month_index_from_name("Bhadra"), Some(5));
+/// assert_eq!(month_index_from_name("asoj"), Some(6));
+/// assert_eq!(month_index_from_name("भाद्र"), Some(5));
+/// assert_eq!(month_index_from_name("Bhadrapada"), None);
+/// ```
+pub fn month_index_from_name(name: &str) -> Option<u8> {
+    let name = name.trim();
+    month_spellings()
+        .find(|(spelling, _)| spelling.eq_ignore_ascii_case(name))
+        .map(|(_, month)| month)
+        .or_else(|| {
+            NEPALI_MONTHS_UNICODE
+                .iter()
+                .position(|&m| m == name)
+                .map(|i| i as u8 + 1)
+        })
+}
+
+/// Returns the canonical romanized name of a month (1-12)
+pub fn month_name(month: u8) -> Option<&'static str> {
+    NepaliMonth::from_number(month).map(NepaliMonth::name)
+}
+
+/// Canonical month names followed by their alternate spellings, with month numbers
+pub(crate) fn month_spellings() -> impl Iterator<Item = (&'static str, u8)> {
+    NEPALI_MONTHS
+        .iter()
+        .enumerate()
+        .map(|(i, &name)| (name, i as u8 + 1))
+        .chain(MONTH_ALIASES.iter().copied())
+}
+
+impl NepaliDate {
+    /// Returns each month of a BS year with its number of days, in order
+    ///
+    /// All twelve lengths are looked up before the iterator is returned, so an
+    /// unsupported year fails here rather than part-way through iteration.
+    ///
+    /// # Examples:
+    /// ```
+    /// # use npdatetime::{NepaliDate, NepaliMonth};
+    /// # if cfg!(any(feature = "lookup-tables", feature = "astronomical")) {
+    /// let mut months = NepaliDate::months_of_year(2077).unwrap();
+    /// assert_eq!(months.next(), Some((NepaliMonth::Baisakh, 31)));
+    /// assert_eq!(months.next(), Some((NepaliMonth::Jestha, 32)));
+    /// # }
+    /// ```
+    pub fn months_of_year(year: i32) -> Result<impl Iterator<Item = (NepaliMonth, u8)>> {
+        let mut lengths = [0u8; 12];
+        for (month, length) in NepaliMonth::ALL.iter().zip(lengths.iter_mut()) {
+            *length = Self::days_in_month(year, month.number())?;
+        }
+        Ok(NepaliMonth::ALL.into_iter().zip(lengths))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_number_round_trip() {
+        for (i, month) in NepaliMonth::ALL.iter().enumerate() {
+            assert_eq!(NepaliMonth::from_number(i as u8 + 1), Some(*month));
+            assert_eq!(month.number() as usize, i + 1);
+        }
+        assert_eq!(NepaliMonth::from_number(0), None);
+        assert_eq!(NepaliMonth::from_number(13), None);
+        assert_eq!(NepaliMonth::Chaitra.to_string(), "Chaitra");
+        assert_eq!(NepaliMonth::Baisakh.unicode_name(), "बैशाख");
+    }
+
+    #[test]
+    fn test_month_name_lookup() {
+        assert_eq!(month_index_from_name("Baisakh"), Some(1));
+        assert_eq!(month_index_from_name("Chaitra"), Some(12));
+        assert_eq!(month_index_from_name("Shravan"), Some(4));
+        assert_eq!(month_index_from_name("Chait"), Some(12));
+        assert_eq!(month_index_from_name("MANGSIR"), Some(8));
+        assert_eq!(month_index_from_name(" fagun "), Some(11));
+        assert_eq!(month_index_from_name("माघ"), Some(10));
+        assert_eq!(month_index_from_name(""), None);
+        assert_eq!(month_index_from_name("January"), None);
+
+        assert_eq!(month_name(1), Some("Baisakh"));
+        assert_eq!(month_name(12), Some("Chaitra"));
+        assert_eq!(month_name(0), None);
+        assert_eq!(month_name(13), None);
+        for month in 1..=12 {
+            assert_eq!(
+                month_index_from_name(month_name(month).unwrap()),
+                Some(month)
+            );
+        }
+    }
+
+    #[cfg(any(feature = "lookup-tables", feature = "astronomical"))]
+    #[test]
+    fn test_months_of_year() {
+        let months: Vec<_> = NepaliDate::months_of_year(2081).unwrap().collect();
+        assert_eq!(
+            months.iter().map(|&(m, _)| m).collect::<Vec<_>>(),
+            NepaliMonth::ALL
+        );
+
+        let total: u16 = months.iter().map(|&(_, len)| len as u16).sum();
+        assert_eq!(total, NepaliDate::days_in_year(2081).unwrap());
+        for (month, len) in months {
+            assert_eq!(
+                len,
+                NepaliDate::days_in_month(2081, month.number()).unwrap()
+            );
+        }
+    }
+}
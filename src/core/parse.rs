@@ -2,18 +2,87 @@
 //!
 //! Provides strptime-like parsing for Nepali date strings.
 
-use crate::core::date::{NEPALI_MONTHS, NepaliDate};
+use crate::core::date::{NEPALI_MONTHS, NEPALI_MONTHS_UNICODE, NepaliDate};
 use crate::core::error::{NpdatetimeError, Result};
+use crate::core::format::abbreviate;
+use crate::core::month::month_spellings;
+
+/// Formats tried by [`NepaliDate::parse_flexible`], in priority order
+pub const FLEXIBLE_FORMATS: [&str; 5] =
+    ["%Y-%m-%d", "%Y/%m/%d", "%d %B %Y", "%B %d %Y", "%d %b %Y"];
+
+/// Common alternate romanizations of month names accepted by `%B`
+///
+/// Each entry maps a spelling to its month number (1-12). Formatting always
+/// uses the canonical names from [`NEPALI_MONTHS`].
+pub const MONTH_ALIASES: [(&str, u8); 17] = [
+    ("Baishakh", 1),
+    ("Jeth", 2),
+    ("Jeshtha", 2),
+    ("Asar", 3),
+    ("Ashar", 3),
+    ("Asadh", 3),
+    ("Saun", 4),
+    ("Shravan", 4),
+    ("Bhadau", 5),
+    ("Asoj", 6),
+    ("Ashoj", 6),
+    ("Mangshir", 8),
+    ("Mansir", 8),
+    ("Push", 9),
+    ("Phagun", 11),
+    ("Fagun", 11),
+    ("Chait", 12),
+];
+
+/// Default centre of the window two-digit years are expanded into
+pub const DEFAULT_TWO_DIGIT_YEAR_PIVOT: i32 = 2075;
+
+/// Options controlling [`NepaliDate::parse_with_options`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ParseOptions {
+    /// A two-digit `%y` year becomes the year ending in those digits within
+    /// `pivot - 50 ..= pivot + 49`. With the default of 2075, "77" is 2077,
+    /// "25" is 2025 and "05" is 2105.
+    pub two_digit_year_pivot: i32,
+}
+
+impl Default for ParseOptions {
+    fn default() -> Self {
+        ParseOptions {
+            two_digit_year_pivot: DEFAULT_TWO_DIGIT_YEAR_PIVOT,
+        }
+    }
+}
+
+impl ParseOptions {
+    /// Expands a two-digit year (0-99) into the pivot window
+    pub fn expand_two_digit_year(&self, yy: i32) -> i32 {
+        let low = self.two_digit_year_pivot - 50;
+        low + (yy - low).rem_euclid(100)
+    }
+}
 
 impl NepaliDate {
     /// Parses a date string into a NepaliDate using a format string
     ///
+    /// Input that does not match the format, or a format without enough
+    /// fields to build a date, gives [`NpdatetimeError::ParseError`] naming
+    /// the input and format positions. Input that matches but names a date
+    /// that does not exist, such as month 13 or Bhadra 33, still gives
+    /// [`NpdatetimeError::InvalidDate`].
+    ///
     /// # Format Specifiers:
     /// - `%Y` - Four-digit year (e.g., 2077)
-    /// - `%m` - Month as decimal (01-12)
-    /// - `%d` - Day as decimal (01-32)
-    /// - `%B` - Full month name in English (e.g., Bhadra)
+    /// - `%y` - Two-digit year, expanded around a pivot (see [`ParseOptions`]);
+    ///   with the default pivot "77" is 2077 and "05" is 2105
+    /// - `%m` - Month as decimal (01-12); the leading zero may be omitted
+    /// - `%d` - Day as decimal (01-32); the leading zero may be omitted
+    /// - `%B` - Full month name in English (e.g., Bhadra); common alternate
+    ///   spellings from [`MONTH_ALIASES`] such as "Asar" or "Asoj" are accepted
     /// - `%b` - Abbreviated month name (first 3 letters)
+    /// - `%j` - Day of the year (1-366), resolved to a month and day with
+    ///   the real month lengths; used instead of `%m`/`%d`
     ///
     /// # Examples:
     /// ```
@@ -26,111 +95,309 @@ impl NepaliDate {
     /// # }
     /// ```
     pub fn parse(input: &str, format: &str) -> Result<Self> {
-        let mut year: Option<i32> = None;
-        let mut month: Option<u8> = None;
-        let mut day: Option<u8> = None;
-
-        let mut input_chars = input.chars().peekable();
-        let mut format_chars = format.chars().peekable();
-
-        while let Some(f) = format_chars.next() {
-            if f == '%' {
-                match format_chars.next() {
-                    Some('Y') => {
-                        let val = consume_digits(&mut input_chars, 4)?;
-                        year = Some(val as i32);
-                    }
-                    Some('m') => {
-                        let val = consume_digits(&mut input_chars, 2)?;
-                        month = Some(val as u8);
-                    }
-                    Some('d') => {
-                        let val = consume_digits(&mut input_chars, 2)?;
-                        day = Some(val as u8);
-                    }
-                    Some('B') => {
-                        let mut found = false;
-                        for (idx, &m_name) in NEPALI_MONTHS.iter().enumerate() {
-                            if peek_match(&mut input_chars, m_name) {
-                                consume_match(&mut input_chars, m_name);
-                                month = Some((idx + 1) as u8);
-                                found = true;
-                                break;
-                            }
-                        }
-                        if !found {
-                            return Err(NpdatetimeError::InvalidDate(
-                                "Failed to parse month name".to_string(),
-                            ));
+        Self::parse_with_options(input, format, &ParseOptions::default())
+    }
+
+    /// Parses like [`NepaliDate::parse`] with explicit [`ParseOptions`]
+    ///
+    /// # Examples:
+    /// ```
+    /// # use npdatetime::NepaliDate;
+    /// # use npdatetime::core::parse::ParseOptions;
+    /// # if cfg!(any(feature = "lookup-tables", feature = "astronomical")) {
+    /// let options = ParseOptions { two_digit_year_pivot: 2000 };
+    /// let date = NepaliDate::parse_with_options("19/05/45", "%d/%m/%y", &options).unwrap();
+    /// assert_eq!(date.year, 2045);
+    /// # }
+    /// ```
+    pub fn parse_with_options(input: &str, format: &str, options: &ParseOptions) -> Result<Self> {
+        match scan_fields(input, format, options)? {
+            Fields {
+                year: Some(y),
+                month: None,
+                day: None,
+                day_of_year: Some(j),
+            } => from_day_of_year(y, j),
+            Fields {
+                year: Some(y),
+                month: Some(m),
+                day: Some(d),
+                day_of_year: None,
+            } => NepaliDate::new(y, m, d),
+            _ => Err(NpdatetimeError::ParseError(
+                "Format needs a year with either month and day or day of year".to_string(),
+            )),
+        }
+    }
+
+    /// Parses a Gregorian (AD) date string and converts it to BS
+    ///
+    /// Only the numeric specifiers `%Y`, `%m`, `%d` and `%%` are accepted,
+    /// since month names and `%y` follow BS conventions.
+    ///
+    /// # Examples:
+    /// ```
+    /// # use npdatetime::NepaliDate;
+    /// # if cfg!(any(feature = "lookup-tables", feature = "astronomical")) {
+    /// let date = NepaliDate::from_gregorian_str("2020-09-04", "%Y-%m-%d").unwrap();
+    /// assert_eq!(date, NepaliDate::new(2077, 5, 19).unwrap());
+    /// # }
+    /// ```
+    pub fn from_gregorian_str(input: &str, format: &str) -> Result<Self> {
+        let mut format_chars = format.chars();
+        while let Some(c) = format_chars.next() {
+            if c != '%' {
+                continue;
+            }
+            match format_chars.next() {
+                Some('Y' | 'm' | 'd' | '%') | None => {}
+                Some(spec) => {
+                    return Err(NpdatetimeError::ParseError(format!(
+                        "'%{}' is not supported for Gregorian dates",
+                        spec
+                    )));
+                }
+            }
+        }
+
+        match scan_fields(input, format, &ParseOptions::default())? {
+            Fields {
+                year: Some(y),
+                month: Some(m),
+                day: Some(d),
+                ..
+            } => NepaliDate::from_gregorian(y, m, d),
+            _ => Err(NpdatetimeError::ParseError(
+                "Missing year, month or day in format".to_string(),
+            )),
+        }
+    }
+
+    /// Parses a date string without an explicit format
+    ///
+    /// Tries each entry of [`FLEXIBLE_FORMATS`] in order and returns the first
+    /// successful parse. Devanagari digits and month names are accepted and
+    /// normalized to their English equivalents before matching.
+    ///
+    /// # Examples:
+    /// ```
+    /// # use npdatetime::NepaliDate;
+    /// # if cfg!(any(feature = "lookup-tables", feature = "astronomical")) {
+    /// let expected = NepaliDate::new(2077, 5, 19).unwrap();
+    /// assert_eq!(NepaliDate::parse_flexible("2077/05/19").unwrap(), expected);
+    /// assert_eq!(NepaliDate::parse_flexible("19 Bhadra 2077").unwrap(), expected);
+    /// assert_eq!(NepaliDate::parse_flexible("१९ भाद्र २०७७").unwrap(), expected);
+    /// # }
+    /// ```
+    pub fn parse_flexible(input: &str) -> Result<Self> {
+        let normalized = normalize_devanagari(input.trim());
+
+        for format in FLEXIBLE_FORMATS {
+            if let Ok(date) = NepaliDate::parse(&normalized, format) {
+                return Ok(date);
+            }
+        }
+
+        Err(NpdatetimeError::ParseError(format!(
+            "Unrecognized date '{}', tried formats: {}",
+            input,
+            FLEXIBLE_FORMATS.join(", ")
+        )))
+    }
+}
+
+/// Date fields read from an input string, each present if its specifier was
+#[derive(Debug, Default)]
+struct Fields {
+    year: Option<i32>,
+    month: Option<u8>,
+    day: Option<u8>,
+    day_of_year: Option<u16>,
+}
+
+/// Reads the date fields of `input` according to `format`
+fn scan_fields(input: &str, format: &str, options: &ParseOptions) -> Result<Fields> {
+    let mut fields = Fields::default();
+
+    let input_len = input.chars().count();
+    let mut input_chars = input.chars().peekable();
+    let mut format_chars = format.chars().enumerate().peekable();
+
+    while let Some((format_pos, f)) = format_chars.next() {
+        // Character offset of the next unread input character
+        let input_pos = input_len - input_chars.clone().count();
+        let error = |msg: String| parse_error(input_pos, format_pos, msg);
+
+        if f == '%' {
+            match format_chars.next().map(|(_, c)| c) {
+                Some('Y') => {
+                    let val = consume_digits(&mut input_chars, 4, 4)
+                        .map_err(|msg| error_at(input_len, &input_chars, format_pos, msg))?;
+                    fields.year = Some(val as i32);
+                }
+                Some('y') => {
+                    let val = consume_digits(&mut input_chars, 2, 2)
+                        .map_err(|msg| error_at(input_len, &input_chars, format_pos, msg))?;
+                    fields.year = Some(options.expand_two_digit_year(val as i32));
+                }
+                Some('m') => {
+                    let val = consume_digits(&mut input_chars, 1, 2)
+                        .map_err(|msg| error_at(input_len, &input_chars, format_pos, msg))?;
+                    fields.month = Some(val as u8);
+                }
+                Some('d') => {
+                    let val = consume_digits(&mut input_chars, 1, 2)
+                        .map_err(|msg| error_at(input_len, &input_chars, format_pos, msg))?;
+                    fields.day = Some(val as u8);
+                }
+                Some('j') => {
+                    let val = consume_digits(&mut input_chars, 1, 3)
+                        .map_err(|msg| error_at(input_len, &input_chars, format_pos, msg))?;
+                    fields.day_of_year = Some(val as u16);
+                }
+                Some('B') => {
+                    // Take the longest matching spelling so "Chait" doesn't cut "Chaitra" short
+                    let best = month_spellings()
+                        .filter(|(m_name, _)| peek_match(&mut input_chars, m_name))
+                        .max_by_key(|(m_name, _)| m_name.len());
+
+                    match best {
+                        Some((m_name, m)) => {
+                            consume_match(&mut input_chars, m_name);
+                            fields.month = Some(m);
                         }
+                        None => return Err(error("expected month name".to_string())),
                     }
-                    Some('b') => {
-                        let mut found = false;
-                        for (idx, &m_name) in NEPALI_MONTHS.iter().enumerate() {
-                            let short_name = &m_name[..3];
-                            if peek_match(&mut input_chars, short_name) {
-                                consume_match(&mut input_chars, short_name);
-                                month = Some((idx + 1) as u8);
-                                found = true;
-                                break;
-                            }
-                        }
-                        if !found {
-                            return Err(NpdatetimeError::InvalidDate(
-                                "Failed to parse abbreviated month name".to_string(),
-                            ));
+                }
+                Some('b') => {
+                    let mut found = false;
+                    for (idx, &m_name) in NEPALI_MONTHS.iter().enumerate() {
+                        let short_name = abbreviate(m_name);
+                        if peek_match(&mut input_chars, &short_name) {
+                            consume_match(&mut input_chars, &short_name);
+                            fields.month = Some((idx + 1) as u8);
+                            found = true;
+                            break;
                         }
                     }
-                    Some('%') => {
-                        if input_chars.next() != Some('%') {
-                            return Err(NpdatetimeError::InvalidDate(
-                                "Literal % mismatch".to_string(),
-                            ));
-                        }
+                    if !found {
+                        return Err(error("expected abbreviated month name".to_string()));
                     }
-                    _ => {
-                        return Err(NpdatetimeError::InvalidDate(
-                            "Invalid format specifier".to_string(),
-                        ));
+                }
+                Some('%') => {
+                    if input_chars.peek() != Some(&'%') {
+                        return Err(error(mismatch('%', input_chars.peek())));
                     }
+                    input_chars.next();
+                }
+                Some(c) => {
+                    return Err(error(format!("invalid format specifier '%{}'", c)));
+                }
+                None => {
+                    return Err(error("format ends with a lone '%'".to_string()));
                 }
-            } else if input_chars.next() != Some(f) {
-                return Err(NpdatetimeError::InvalidDate(format!(
-                    "Character mismatch: expected {}",
-                    f
-                )));
             }
+        } else if input_chars.peek() != Some(&f) {
+            return Err(error(mismatch(f, input_chars.peek())));
+        } else {
+            input_chars.next();
         }
+    }
 
-        match (year, month, day) {
-            (Some(y), Some(m), Some(d)) => NepaliDate::new(y, m, d),
-            _ => Err(NpdatetimeError::InvalidDate(
-                "Missing year, month or day in format".to_string(),
-            )),
+    Ok(fields)
+}
+
+/// Resolves a day of the year (1 for Baisakh 1) to a date
+fn from_day_of_year(year: i32, day_of_year: u16) -> Result<NepaliDate> {
+    let year_days = NepaliDate::days_in_year(year)?;
+    if !(1..=year_days).contains(&day_of_year) {
+        return Err(NpdatetimeError::InvalidDate(format!(
+            "Day of year {} is outside 1-{} for year {}",
+            day_of_year, year_days, year
+        )));
+    }
+
+    let mut remaining = day_of_year;
+    for month in 1..=12 {
+        let month_days = NepaliDate::days_in_month(year, month)? as u16;
+        if remaining <= month_days {
+            return NepaliDate::new(year, month, remaining as u8);
+        }
+        remaining -= month_days;
+    }
+    unreachable!("day of year checked against days_in_year")
+}
+
+/// Replaces Devanagari digits and month names with their ASCII/English forms
+fn normalize_devanagari(input: &str) -> String {
+    const DEVANAGARI_DIGITS: [char; 10] = ['०', '१', '२', '३', '४', '५', '६', '७', '८', '९'];
+
+    let mut result: String = input
+        .chars()
+        .map(|c| match DEVANAGARI_DIGITS.iter().position(|&d| d == c) {
+            Some(digit) => (b'0' + digit as u8) as char,
+            None => c,
+        })
+        .collect();
+
+    for (idx, m_name) in NEPALI_MONTHS_UNICODE.iter().enumerate() {
+        if result.contains(m_name) {
+            result = result.replace(m_name, NEPALI_MONTHS[idx]);
         }
     }
+
+    result
+}
+
+/// Builds a parse error locating the failure in both the input and the format
+fn parse_error(input_pos: usize, format_pos: usize, msg: String) -> NpdatetimeError {
+    NpdatetimeError::ParseError(format!(
+        "at position {} (format position {}): {}",
+        input_pos, format_pos, msg
+    ))
+}
+
+/// Like [`parse_error`], taking the input position from where `it` stopped
+fn error_at(
+    input_len: usize,
+    it: &std::iter::Peekable<std::str::Chars>,
+    format_pos: usize,
+    msg: String,
+) -> NpdatetimeError {
+    parse_error(input_len - it.clone().count(), format_pos, msg)
+}
+
+fn mismatch(expected: char, found: Option<&char>) -> String {
+    match found {
+        Some(c) => format!("expected '{}', found '{}'", expected, c),
+        None => format!("expected '{}', found end of input", expected),
+    }
 }
 
-fn consume_digits(it: &mut std::iter::Peekable<std::str::Chars>, count: usize) -> Result<u32> {
-    let mut s = String::new();
-    for _ in 0..count {
-        if let Some(c) = it.next() {
-            if c.is_ascii_digit() {
-                s.push(c);
-            } else {
-                return Err(NpdatetimeError::InvalidDate(format!(
-                    "Expected digit, got {}",
-                    c
-                )));
+/// Consumes between `min` and `max` ASCII digits, leaving the iterator on the
+/// offending character if too few are found
+///
+/// Reading stops early only at a separator (anything but a letter or digit)
+/// or the end of input, so "5-9" reads as 5 but "0x" is still rejected.
+fn consume_digits(
+    it: &mut std::iter::Peekable<std::str::Chars>,
+    min: usize,
+    max: usize,
+) -> std::result::Result<u32, String> {
+    let mut value = 0u32;
+    for read in 0..max {
+        match it.peek() {
+            Some(c) if c.is_ascii_digit() => {
+                value = value * 10 + c.to_digit(10).unwrap_or(0);
+                it.next();
             }
-        } else {
-            return Err(NpdatetimeError::InvalidDate(
-                "Unexpected end of input".to_string(),
-            ));
+            Some(c) if read >= min && !c.is_alphanumeric() => break,
+            None if read >= min => break,
+            Some(c) => return Err(format!("expected digit, found '{}'", c)),
+            None => return Err("expected digit, found end of input".to_string()),
         }
     }
-    s.parse::<u32>()
-        .map_err(|e| NpdatetimeError::InvalidDate(e.to_string()))
+    Ok(value)
 }
 
 fn peek_match(it: &mut std::iter::Peekable<std::str::Chars>, target: &str) -> bool {
@@ -153,6 +420,25 @@ fn consume_match(it: &mut std::iter::Peekable<std::str::Chars>, target: &str) {
 mod tests {
     use super::*;
 
+    #[cfg(any(feature = "lookup-tables", feature = "astronomical"))]
+    #[test]
+    fn test_from_gregorian_str() {
+        let expected = NepaliDate::new(2077, 5, 19).unwrap();
+        assert_eq!(
+            NepaliDate::from_gregorian_str("2020-09-04", "%Y-%m-%d").unwrap(),
+            expected
+        );
+        assert_eq!(
+            NepaliDate::from_gregorian_str("2020/9/4", "%Y/%m/%d").unwrap(),
+            expected
+        );
+        assert!(NepaliDate::from_gregorian_str("2020-02-30", "%Y-%m-%d").is_err());
+        assert!(matches!(
+            NepaliDate::from_gregorian_str("04 Sep 2020", "%d %b %Y"),
+            Err(NpdatetimeError::ParseError(_))
+        ));
+    }
+
     #[cfg(any(feature = "lookup-tables", feature = "astronomical"))]
     #[test]
     fn test_parse_iso() {
@@ -162,6 +448,66 @@ mod tests {
         assert_eq!(date.day, 19);
     }
 
+    #[cfg(any(feature = "lookup-tables", feature = "astronomical"))]
+    #[test]
+    fn test_parse_unpadded_fields() {
+        let padded = NepaliDate::parse("2077-05-09", "%Y-%m-%d").unwrap();
+        assert_eq!(NepaliDate::parse("2077-5-9", "%Y-%m-%d").unwrap(), padded);
+        assert_eq!(
+            NepaliDate::parse("9 Bhadra 2077", "%d %B %Y").unwrap(),
+            padded
+        );
+        assert_eq!(NepaliDate::parse("2077/5/09", "%Y/%m/%d").unwrap(), padded);
+
+        // Years stay four digits, and a lone digit cannot run into a letter
+        assert!(NepaliDate::parse("207-5-9", "%Y-%m-%d").is_err());
+        assert!(NepaliDate::parse("2077-5x-9", "%Y-%m-%d").is_err());
+    }
+
+    #[cfg(any(feature = "lookup-tables", feature = "astronomical"))]
+    #[test]
+    fn test_parse_two_digit_year() {
+        let date = NepaliDate::parse("77-05-19", "%y-%m-%d").unwrap();
+        assert_eq!(date, NepaliDate::new(2077, 5, 19).unwrap());
+
+        let date = NepaliDate::parse("99-01-01", "%y-%m-%d").unwrap();
+        assert_eq!(date.year, 2099);
+
+        let options = ParseOptions {
+            two_digit_year_pivot: 2030,
+        };
+        let date = NepaliDate::parse_with_options("05-01-01", "%y-%m-%d", &options).unwrap();
+        assert_eq!(date.year, 2005);
+
+        assert!(NepaliDate::parse("7-05-19", "%y-%m-%d").is_err());
+    }
+
+    #[test]
+    fn test_two_digit_year_window() {
+        let options = ParseOptions::default();
+        assert_eq!(options.expand_two_digit_year(77), 2077);
+        assert_eq!(options.expand_two_digit_year(25), 2025);
+        assert_eq!(options.expand_two_digit_year(24), 2124);
+        assert_eq!(options.expand_two_digit_year(5), 2105);
+    }
+
+    #[cfg(any(feature = "lookup-tables", feature = "astronomical"))]
+    #[test]
+    fn test_parse_day_of_year() {
+        // 2077: Baisakh 31, Jestha 32, Ashadh 31, Shrawan 32
+        let date = NepaliDate::parse("2077-139", "%Y-%j").unwrap();
+        assert_eq!((date.year, date.month, date.day), (2077, 5, 13));
+        assert_eq!(date.day_of_year().unwrap(), 139);
+        assert_eq!(NepaliDate::parse("2077-1", "%Y-%j").unwrap().day, 1);
+
+        let year_days = NepaliDate::days_in_year(2077).unwrap();
+        let last = NepaliDate::parse(&format!("2077-{}", year_days), "%Y-%j").unwrap();
+        assert_eq!((last.month, last.day_of_year().unwrap()), (12, year_days));
+        assert!(NepaliDate::parse(&format!("2077-{}", year_days + 1), "%Y-%j").is_err());
+        assert!(NepaliDate::parse("2077-000", "%Y-%j").is_err());
+        assert!(NepaliDate::parse("2077-05-139", "%Y-%m-%j").is_err());
+    }
+
     #[cfg(any(feature = "lookup-tables", feature = "astronomical"))]
     #[test]
     fn test_parse_month_name() {
@@ -171,6 +517,23 @@ mod tests {
         assert_eq!(date.day, 19);
     }
 
+    #[cfg(any(feature = "lookup-tables", feature = "astronomical"))]
+    #[test]
+    fn test_parse_month_aliases() {
+        let date = NepaliDate::parse("15 Asar 2080", "%d %B %Y").unwrap();
+        assert_eq!(date.month, 3);
+
+        let date = NepaliDate::parse("15 Asoj 2080", "%d %B %Y").unwrap();
+        assert_eq!(date.month, 6);
+        assert_eq!(date.format_date("%B"), "Ashwin");
+
+        // A shorter alias must not shadow the canonical spelling
+        let date = NepaliDate::parse("15 Chaitra 2080", "%d %B %Y").unwrap();
+        assert_eq!(date.month, 12);
+        let date = NepaliDate::parse("15 Chait 2080", "%d %B %Y").unwrap();
+        assert_eq!(date.month, 12);
+    }
+
     #[cfg(any(feature = "lookup-tables", feature = "astronomical"))]
     #[test]
     fn test_parse_abbrev_month() {
@@ -180,9 +543,86 @@ mod tests {
         assert_eq!(date.day, 19);
     }
 
+    #[cfg(any(feature = "lookup-tables", feature = "astronomical"))]
+    #[test]
+    fn test_parse_flexible_accepted_forms() {
+        let expected = NepaliDate::new(2077, 5, 19).unwrap();
+        for input in [
+            "2077-05-19",
+            "2077/05/19",
+            "19 Bhadra 2077",
+            "Bhadra 19 2077",
+            "19 Bha 2077",
+            "२०७७-०५-१९",
+            "१९ भाद्र २०७७",
+            "  2077-05-19 ",
+        ] {
+            assert_eq!(
+                NepaliDate::parse_flexible(input).unwrap(),
+                expected,
+                "{}",
+                input
+            );
+        }
+    }
+
+    #[test]
+    fn test_parse_flexible_rejects_garbage() {
+        for input in ["", "hello", "19-05", "05/19/2077"] {
+            match NepaliDate::parse_flexible(input) {
+                Err(NpdatetimeError::ParseError(msg)) => assert!(msg.contains("%Y-%m-%d")),
+                other => panic!("expected ParseError for {:?}, got {:?}", input, other),
+            }
+        }
+    }
+
     #[test]
     fn test_parse_mismatch() {
         let res = NepaliDate::parse("2077-05-19", "%Y/%m/%d");
         assert!(res.is_err());
     }
+
+    #[cfg(any(feature = "lookup-tables", feature = "astronomical"))]
+    #[test]
+    fn test_parse_out_of_range_is_invalid_date() {
+        for input in ["2077-13-01", "2077-00-10", "2077-05-33", "2077-05-00"] {
+            match NepaliDate::parse(input, "%Y-%m-%d") {
+                Err(NpdatetimeError::InvalidDate(_)) => {}
+                other => panic!("expected InvalidDate for {:?}, got {:?}", input, other),
+            }
+        }
+
+        match NepaliDate::parse("2077 400", "%Y %j") {
+            Err(NpdatetimeError::InvalidDate(_)) => {}
+            other => panic!("expected InvalidDate, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_parse_error_position() {
+        match NepaliDate::parse("2077-05-19", "%Y/%m/%d") {
+            Err(NpdatetimeError::ParseError(msg)) => {
+                assert_eq!(
+                    msg,
+                    "at position 4 (format position 2): expected '/', found '-'"
+                )
+            }
+            other => panic!("expected ParseError, got {:?}", other),
+        }
+
+        match NepaliDate::parse("2077-0x-19", "%Y-%m-%d") {
+            Err(NpdatetimeError::ParseError(msg)) => {
+                assert_eq!(
+                    msg,
+                    "at position 6 (format position 3): expected digit, found 'x'"
+                )
+            }
+            other => panic!("expected ParseError, got {:?}", other),
+        }
+
+        match NepaliDate::parse("2077-05", "%Y-%m-%d") {
+            Err(NpdatetimeError::ParseError(msg)) => assert!(msg.starts_with("at position 7 ")),
+            other => panic!("expected ParseError, got {:?}", other),
+        }
+    }
 }
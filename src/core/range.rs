@@ -0,0 +1,346 @@
+//! Day-by-day and recurring iteration over Nepali dates
+
+use crate::core::date::NepaliDate;
+use std::iter::FusedIterator;
+
+/// Iterator over consecutive days from `start` to `end`
+///
+/// [`NepaliDateRange::new`] and [`NepaliDate::range_inclusive`] include
+/// `end`; [`NepaliDate::range`] stops the day before it.
+///
+/// Days are stepped through the BS month lengths directly, so no Gregorian
+/// conversion happens per item. Iteration stops early if it walks past the
+/// supported calendar range.
+///
+/// # Examples:
+/// ```
+/// # use npdatetime::{NepaliDate, NepaliDateRange};
+/// # if cfg!(any(feature = "lookup-tables", feature = "astronomical")) {
+/// let start = NepaliDate::new(2077, 5, 30).unwrap();
+/// let end = NepaliDate::new(2077, 6, 2).unwrap();
+/// let days: Vec<String> = NepaliDateRange::new(start, end).map(|d| d.to_string()).collect();
+/// assert_eq!(days, ["2077-05-30", "2077-05-31", "2077-06-01", "2077-06-02"]);
+/// # }
+/// ```
+#[derive(Debug, Clone)]
+pub struct NepaliDateRange {
+    next: Option<NepaliDate>,
+    end: NepaliDate,
+    inclusive: bool,
+}
+
+impl NepaliDateRange {
+    /// Creates an iterator over `start..=end`; empty if `start > end`
+    pub fn new(start: NepaliDate, end: NepaliDate) -> Self {
+        NepaliDateRange {
+            next: (start <= end).then_some(start),
+            end,
+            inclusive: true,
+        }
+    }
+
+    /// Creates an iterator over `start..end`; empty if `start >= end`
+    fn half_open(start: NepaliDate, end: NepaliDate) -> Self {
+        NepaliDateRange {
+            next: (start < end).then_some(start),
+            end,
+            inclusive: false,
+        }
+    }
+
+    /// Whether `date` is still before the end of the range
+    fn admits(&self, date: &NepaliDate) -> bool {
+        if self.inclusive {
+            *date <= self.end
+        } else {
+            *date < self.end
+        }
+    }
+}
+
+/// Returns the day after `date`, or `None` past the supported range
+fn next_day(date: NepaliDate) -> Option<NepaliDate> {
+    let NepaliDate { year, month, day } = date;
+    if day < NepaliDate::days_in_month(year, month).ok()? {
+        Some(NepaliDate {
+            year,
+            month,
+            day: day + 1,
+        })
+    } else if month < 12 {
+        Some(NepaliDate {
+            year,
+            month: month + 1,
+            day: 1,
+        })
+    } else {
+        // Probe the next year so iteration ends at the edge of the table
+        NepaliDate::days_in_month(year + 1, 1).ok()?;
+        Some(NepaliDate {
+            year: year + 1,
+            month: 1,
+            day: 1,
+        })
+    }
+}
+
+impl Iterator for NepaliDateRange {
+    type Item = NepaliDate;
+
+    fn next(&mut self) -> Option<NepaliDate> {
+        let current = self.next?;
+        self.next = next_day(current).filter(|next| self.admits(next));
+        Some(current)
+    }
+}
+
+impl FusedIterator for NepaliDateRange {}
+
+/// Distance between consecutive items of a [`NepaliDateStep`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Stride {
+    Days(u32),
+    Months(u32),
+}
+
+/// Unbounded iterator over a recurring schedule, as returned by
+/// [`NepaliDate::step_by_days`], [`NepaliDate::step_by_weeks`] and
+/// [`NepaliDate::step_by_months`]
+///
+/// Bound it with [`Iterator::take`] or [`Iterator::take_while`]; it ends by
+/// itself only when the next occurrence leaves the supported calendar range.
+#[derive(Debug, Clone)]
+pub struct NepaliDateStep {
+    start: NepaliDate,
+    next: Option<NepaliDate>,
+    stride: Stride,
+    taken: u32,
+}
+
+impl NepaliDateStep {
+    fn new(start: NepaliDate, stride: Stride) -> Self {
+        let n = match stride {
+            Stride::Days(n) | Stride::Months(n) => n,
+        };
+        assert!(n != 0, "step must be non-zero");
+        NepaliDateStep {
+            start,
+            next: Some(start),
+            stride,
+            taken: 0,
+        }
+    }
+}
+
+impl Iterator for NepaliDateStep {
+    type Item = NepaliDate;
+
+    fn next(&mut self) -> Option<NepaliDate> {
+        let current = self.next?;
+        self.taken += 1;
+        self.next = match self.stride {
+            Stride::Days(n) => (0..n).try_fold(current, |date, _| next_day(date)),
+            Stride::Months(n) => {
+                // Offset from the start rather than the previous item, so a
+                // day clamped in a short month recovers in the next long one
+                let months = (self.start.month as i64 - 1) + self.taken as i64 * n as i64;
+                let year = i32::try_from(self.start.year as i64 + months.div_euclid(12)).ok();
+                year.and_then(|year| {
+                    let month = months.rem_euclid(12) as u8 + 1;
+                    NepaliDate::new_clamped(year, month, self.start.day).ok()
+                })
+            }
+        };
+        Some(current)
+    }
+}
+
+impl FusedIterator for NepaliDateStep {}
+
+impl NepaliDate {
+    /// Iterates over the days from `start` up to, but not including, `end`
+    ///
+    /// Like Rust's `start..end`: the range is empty when `start >= end`, and
+    /// passing the first day of the next month covers exactly one month. Use
+    /// [`NepaliDate::range_inclusive`] to include `end`.
+    ///
+    /// # Examples:
+    /// ```
+    /// # use npdatetime::NepaliDate;
+    /// # if cfg!(any(feature = "lookup-tables", feature = "astronomical")) {
+    /// let start = NepaliDate::new(2077, 5, 1).unwrap();
+    /// let end = NepaliDate::new(2077, 6, 1).unwrap();
+    /// assert_eq!(NepaliDate::range(start, end).count(), 31);
+    /// assert_eq!(NepaliDate::range_inclusive(start, end).count(), 32);
+    /// # }
+    /// ```
+    pub fn range(start: NepaliDate, end: NepaliDate) -> NepaliDateRange {
+        NepaliDateRange::half_open(start, end)
+    }
+
+    /// Iterates over the days from `start` through `end`, both included
+    ///
+    /// Like Rust's `start..=end`: the range is empty when `start > end` and
+    /// holds one day when they are equal. Same as [`NepaliDateRange::new`].
+    pub fn range_inclusive(start: NepaliDate, end: NepaliDate) -> NepaliDateRange {
+        NepaliDateRange::new(start, end)
+    }
+
+    /// Iterates from this date in steps of `n` days
+    ///
+    /// # Panics
+    /// Panics if `n` is 0.
+    pub fn step_by_days(&self, n: u32) -> NepaliDateStep {
+        NepaliDateStep::new(*self, Stride::Days(n))
+    }
+
+    /// Iterates from this date in steps of `n` weeks, so every item falls on
+    /// the same weekday
+    ///
+    /// # Examples:
+    /// ```
+    /// # use npdatetime::NepaliDate;
+    /// # if cfg!(any(feature = "lookup-tables", feature = "astronomical")) {
+    /// let standups: Vec<String> = NepaliDate::new(2081, 1, 3)
+    ///     .unwrap()
+    ///     .step_by_weeks(1)
+    ///     .take(3)
+    ///     .map(|d| d.to_string())
+    ///     .collect();
+    /// assert_eq!(standups, ["2081-01-03", "2081-01-10", "2081-01-17"]);
+    /// # }
+    /// ```
+    ///
+    /// # Panics
+    /// Panics if `n` is 0.
+    pub fn step_by_weeks(&self, n: u32) -> NepaliDateStep {
+        NepaliDateStep::new(
+            *self,
+            Stride::Days(n.checked_mul(7).expect("step overflows")),
+        )
+    }
+
+    /// Iterates from this date in steps of `n` months, on the same day of
+    /// month
+    ///
+    /// Like [`NepaliDate::new_clamped`], the day is clamped to the length of
+    /// shorter months, and returns to the original day in longer ones.
+    ///
+    /// # Panics
+    /// Panics if `n` is 0.
+    pub fn step_by_months(&self, n: u32) -> NepaliDateStep {
+        NepaliDateStep::new(*self, Stride::Months(n))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[cfg(any(feature = "lookup-tables", feature = "astronomical"))]
+    #[test]
+    fn test_range_crosses_year_end() {
+        let start = NepaliDate::new(2080, 12, 29).unwrap();
+        let end = NepaliDate::new(2081, 1, 2).unwrap();
+        let days: Vec<_> = NepaliDateRange::new(start, end).collect();
+
+        assert_eq!(days.len() as i64, start.days_between(&end).unwrap() + 1);
+        assert_eq!(days.first(), Some(&start));
+        assert_eq!(days.last(), Some(&end));
+        assert!(days.windows(2).all(|w| w[0] < w[1]));
+    }
+
+    #[cfg(any(feature = "lookup-tables", feature = "astronomical"))]
+    #[test]
+    fn test_half_open_and_inclusive_ranges() {
+        // Ashwin 2077 has 30 days
+        let start = NepaliDate::new(2077, 6, 1).unwrap();
+        let next_month = NepaliDate::new(2077, 7, 1).unwrap();
+
+        let month: Vec<_> = NepaliDate::range(start, next_month).collect();
+        assert_eq!(month.len(), 30);
+        assert_eq!(month.last(), Some(&NepaliDate::new(2077, 6, 30).unwrap()));
+        assert_eq!(
+            NepaliDate::range_inclusive(start, next_month).count(),
+            month.len() + 1
+        );
+
+        assert_eq!(NepaliDate::range(start, start).count(), 0);
+        assert_eq!(NepaliDate::range_inclusive(start, start).count(), 1);
+        assert_eq!(NepaliDate::range(next_month, start).count(), 0);
+    }
+
+    #[test]
+    fn test_empty_when_reversed() {
+        let a = NepaliDate {
+            year: 2081,
+            month: 1,
+            day: 2,
+        };
+        let b = NepaliDate {
+            year: 2081,
+            month: 1,
+            day: 1,
+        };
+        assert_eq!(NepaliDateRange::new(a, b).count(), 0);
+        assert_eq!(NepaliDateRange::new(a, a).count(), 1);
+    }
+
+    #[cfg(any(feature = "lookup-tables", feature = "astronomical"))]
+    #[test]
+    fn test_weekly_steps_keep_weekday() {
+        let start = NepaliDate::new(2080, 11, 20).unwrap();
+        let weekday = start.weekday().unwrap();
+        let dates: Vec<_> = start.step_by_weeks(2).take(10).collect();
+
+        assert_eq!(dates.len(), 10);
+        assert!(dates.iter().all(|d| d.weekday().unwrap() == weekday));
+        assert!(
+            dates
+                .windows(2)
+                .all(|w| w[0].days_between(&w[1]).unwrap() == 14)
+        );
+        assert_eq!(start.step_by_days(14).nth(9), Some(dates[9]));
+    }
+
+    #[cfg(any(feature = "lookup-tables", feature = "astronomical"))]
+    #[test]
+    fn test_monthly_steps_clamp_day() {
+        // 2081 month lengths: 31,32,31,32,31,30,30,30,29,30,29,31
+        let start = NepaliDate::new(2081, 5, 31).unwrap();
+        let days: Vec<(u8, u8)> = start
+            .step_by_months(1)
+            .take(9)
+            .map(|d| (d.month, d.day))
+            .collect();
+        assert_eq!(
+            days,
+            [
+                (5, 31),
+                (6, 30),
+                (7, 30),
+                (8, 30),
+                (9, 29),
+                (10, 30),
+                (11, 29),
+                (12, 31),
+                (1, 31)
+            ]
+        );
+
+        let quarterly: Vec<String> = start
+            .step_by_months(3)
+            .take(3)
+            .map(|d| d.to_string())
+            .collect();
+        assert_eq!(quarterly, ["2081-05-31", "2081-08-30", "2081-11-29"]);
+    }
+
+    #[cfg(all(feature = "lookup-tables", not(feature = "astronomical")))]
+    #[test]
+    fn test_steps_end_at_range_edge() {
+        let start = NepaliDate::new(2100, 10, 1).unwrap();
+        assert_eq!(start.step_by_months(1).count(), 3);
+        assert_eq!(start.step_by_weeks(1).last().unwrap().year, 2100);
+    }
+}
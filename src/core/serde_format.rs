@@ -0,0 +1,104 @@
+//! Selectable serde representations for [`NepaliDate`]
+//!
+//! Use with `#[serde(with = "...")]` on a field to pick the wire format:
+//! - [`string`] writes `"2077-05-19"`
+//! - [`object`] writes `{"year":2077,"month":5,"day":19}`
+//!
+//! Both validate on deserialization through [`NepaliDate::new`], unlike the
+//! derived impl on `NepaliDate`, which accepts any component values.
+//!
+//! # Examples:
+//! ```
+//! use npdatetime::NepaliDate;
+//! use serde::{Deserialize, Serialize};
+//!
+//! #[derive(Serialize, Deserialize)]
+//! struct Invoice {
+//!     #[serde(with = "npdatetime::core::serde_format::string")]
+//!     issued: NepaliDate,
+//!     #[serde(with = "npdatetime::core::serde_format::object")]
+//!     due: NepaliDate,
+//! }
+//! ```
+
+use crate::core::date::NepaliDate;
+use serde::{Deserialize, Deserializer, Serializer, de::Error};
+
+/// `"YYYY-MM-DD"` string form
+pub mod string {
+    use super::*;
+
+    pub fn serialize<S: Serializer>(date: &NepaliDate, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(date)
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<NepaliDate, D::Error> {
+        let s = String::deserialize(deserializer)?;
+        NepaliDate::parse(&s, "%Y-%m-%d").map_err(D::Error::custom)
+    }
+}
+
+/// `{"year", "month", "day"}` object form
+pub mod object {
+    use super::*;
+    use serde::Serialize;
+
+    #[derive(Serialize, Deserialize)]
+    struct Components {
+        year: i32,
+        month: u8,
+        day: u8,
+    }
+
+    pub fn serialize<S: Serializer>(date: &NepaliDate, serializer: S) -> Result<S::Ok, S::Error> {
+        Components {
+            year: date.year,
+            month: date.month,
+            day: date.day,
+        }
+        .serialize(serializer)
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<NepaliDate, D::Error> {
+        let c = Components::deserialize(deserializer)?;
+        NepaliDate::new(c.year, c.month, c.day).map_err(D::Error::custom)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde::Serialize;
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct AsString(#[serde(with = "string")] NepaliDate);
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct AsObject(#[serde(with = "object")] NepaliDate);
+
+    #[cfg(any(feature = "lookup-tables", feature = "astronomical"))]
+    #[test]
+    fn test_both_representations_round_trip() {
+        let date = NepaliDate::new(2077, 5, 19).unwrap();
+
+        let json = serde_json::to_string(&AsString(date)).unwrap();
+        assert_eq!(json, r#""2077-05-19""#);
+        assert_eq!(
+            serde_json::from_str::<AsString>(&json).unwrap(),
+            AsString(date)
+        );
+
+        let json = serde_json::to_string(&AsObject(date)).unwrap();
+        assert_eq!(json, r#"{"year":2077,"month":5,"day":19}"#);
+        assert_eq!(
+            serde_json::from_str::<AsObject>(&json).unwrap(),
+            AsObject(date)
+        );
+    }
+
+    #[test]
+    fn test_invalid_components_rejected() {
+        assert!(serde_json::from_str::<AsObject>(r#"{"year":2077,"month":13,"day":1}"#).is_err());
+        assert!(serde_json::from_str::<AsString>(r#""2077-13-01""#).is_err());
+    }
+}
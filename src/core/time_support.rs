@@ -0,0 +1,78 @@
+//! Conversions between [`NepaliDate`] and the `time` crate's [`Date`]
+//!
+//! Both directions go through the Gregorian conversion of
+//! [`NepaliDate::from_gregorian`] and [`NepaliDate::to_gregorian`].
+
+use crate::core::date::NepaliDate;
+use crate::core::error::{NpdatetimeError, Result};
+use time::{Date, Month};
+
+/// Converts the Gregorian date to BS
+///
+/// Dates outside the supported calendar range fail with `OutOfRange`.
+///
+/// # Examples:
+/// ```
+/// # use npdatetime::NepaliDate;
+/// # if cfg!(any(feature = "lookup-tables", feature = "astronomical")) {
+/// let ad = time::Date::from_calendar_date(2020, time::Month::September, 4).unwrap();
+/// let bs = NepaliDate::try_from(ad).unwrap();
+/// assert_eq!(bs.to_string(), "2077-05-19");
+/// assert_eq!(bs.to_time_date().unwrap(), ad);
+/// # }
+/// ```
+impl TryFrom<Date> for NepaliDate {
+    type Error = NpdatetimeError;
+
+    fn try_from(date: Date) -> Result<Self> {
+        NepaliDate::from_gregorian(date.year(), date.month() as u8, date.day()).map_err(|e| match e
+        {
+            NpdatetimeError::BeforeEpoch(msg) | NpdatetimeError::AfterSupportedRange(msg) => {
+                NpdatetimeError::OutOfRange(msg)
+            }
+            other => other,
+        })
+    }
+}
+
+impl NepaliDate {
+    /// Converts the date to a `time::Date` in the Gregorian calendar
+    pub fn to_time_date(&self) -> Result<Date> {
+        let (year, month, day) = self.to_gregorian()?;
+        Month::try_from(month)
+            .and_then(|month| Date::from_calendar_date(year, month, day))
+            .map_err(|e| NpdatetimeError::OutOfRange(e.to_string()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[cfg(any(feature = "lookup-tables", feature = "astronomical"))]
+    #[test]
+    fn test_known_pairs() {
+        let pairs = [
+            ((1975, 1, 1), (1918, Month::April, 13)),
+            ((2077, 5, 19), (2020, Month::September, 4)),
+            ((2081, 1, 1), (2024, Month::April, 13)),
+            ((2081, 12, 31), (2025, Month::April, 13)),
+        ];
+        for ((y, m, d), (ay, am, ad)) in pairs {
+            let bs = NepaliDate::new(y, m, d).unwrap();
+            let ad = Date::from_calendar_date(ay, am, ad).unwrap();
+            assert_eq!(NepaliDate::try_from(ad).unwrap(), bs);
+            assert_eq!(bs.to_time_date().unwrap(), ad);
+        }
+    }
+
+    #[cfg(all(feature = "lookup-tables", not(feature = "astronomical")))]
+    #[test]
+    fn test_pre_epoch_is_out_of_range() {
+        let ad = Date::from_calendar_date(1900, Month::January, 1).unwrap();
+        assert!(matches!(
+            NepaliDate::try_from(ad),
+            Err(NpdatetimeError::OutOfRange(_))
+        ));
+    }
+}
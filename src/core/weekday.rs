@@ -0,0 +1,408 @@
+//! Days of the week and weekday-based navigation for Nepali dates
+
+use crate::core::date::{NEPALI_WEEKDAYS, NepaliDate};
+use crate::core::error::Result;
+use crate::core::format::calculate_weekday;
+use crate::core::range::NepaliDateRange;
+use std::fmt;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Day of the week, numbered from Sunday (Aaitabaar) as in the Nepali week
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum Weekday {
+    Sunday,
+    Monday,
+    Tuesday,
+    Wednesday,
+    Thursday,
+    Friday,
+    Saturday,
+}
+
+impl Weekday {
+    /// All weekdays in order, starting with Sunday
+    pub const ALL: [Weekday; 7] = [
+        Weekday::Sunday,
+        Weekday::Monday,
+        Weekday::Tuesday,
+        Weekday::Wednesday,
+        Weekday::Thursday,
+        Weekday::Friday,
+        Weekday::Saturday,
+    ];
+
+    /// Creates a weekday from its index (0 = Sunday, 6 = Saturday)
+    pub fn from_index(index: u8) -> Option<Self> {
+        Self::ALL.get(index as usize).copied()
+    }
+
+    /// Returns the index of the weekday (0 = Sunday, 6 = Saturday)
+    pub fn index(self) -> u8 {
+        self as u8
+    }
+
+    /// English name of the weekday (e.g. "Sunday")
+    pub fn name(self) -> &'static str {
+        match self {
+            Weekday::Sunday => "Sunday",
+            Weekday::Monday => "Monday",
+            Weekday::Tuesday => "Tuesday",
+            Weekday::Wednesday => "Wednesday",
+            Weekday::Thursday => "Thursday",
+            Weekday::Friday => "Friday",
+            Weekday::Saturday => "Saturday",
+        }
+    }
+
+    /// Romanized Nepali name of the weekday (e.g. "Aaitabaar")
+    pub fn nepali_name(self) -> &'static str {
+        NEPALI_WEEKDAYS[self as usize]
+    }
+}
+
+impl fmt::Display for Weekday {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.pad(self.name())
+    }
+}
+
+impl NepaliDate {
+    /// Weekday of the epoch, 1975-01-01 BS (Saturday 1918-04-13 AD)
+    ///
+    /// Weekday arithmetic on ordinals counts on from this day.
+    pub const EPOCH_WEEKDAY: Weekday = Weekday::Saturday;
+
+    /// Returns the weekday of Baisakh 1 of the given BS year
+    ///
+    /// Within the supported range this counts on from the epoch's ordinal
+    /// (see [`NepaliDate::weekday_index_fast`]), so week numbering over many
+    /// dates of the same year needs no Gregorian conversion.
+    pub fn year_start_weekday(year: i32) -> Result<Weekday> {
+        NepaliDate::new(year, 1, 1)?.weekday()
+    }
+
+    /// Returns the week of the year (1-based), with weeks starting on Sunday
+    ///
+    /// Week 1 is the (possibly partial) week containing Baisakh 1, matching
+    /// the rows of a wall-calendar grid.
+    pub fn week_of_year(&self) -> Result<u16> {
+        let first = Self::year_start_weekday(self.year)?.index() as u16;
+        Ok((self.day_of_year()? - 1 + first) / 7 + 1)
+    }
+
+    /// Index of the Sunday-to-Saturday week containing this date, counted
+    /// from the epoch
+    ///
+    /// The epoch 1975-01-01 BS is a Saturday ([`NepaliDate::EPOCH_WEEKDAY`]),
+    /// so it alone makes up week 0 and week 1 starts on the following
+    /// Sunday. Unlike [`NepaliDate::week_of_year`] the count does not reset
+    /// at Baisakh 1, giving stable weekly buckets across years. Pre-epoch
+    /// dates get negative weeks.
+    ///
+    /// # Examples:
+    /// ```
+    /// # use npdatetime::NepaliDate;
+    /// # if cfg!(any(feature = "lookup-tables", feature = "astronomical")) {
+    /// // Chaitra 30 2080 (Friday) and Baisakh 1 2081 (Saturday) share a week
+    /// let last = NepaliDate::new(2080, 12, 30).unwrap();
+    /// let first = NepaliDate::new(2081, 1, 1).unwrap();
+    /// assert_eq!(last.ordinal_week().unwrap(), first.ordinal_week().unwrap());
+    /// # }
+    /// ```
+    pub fn ordinal_week(&self) -> Result<i32> {
+        Ok((self.try_to_ordinal()? - 1 + Self::EPOCH_WEEKDAY.index() as i32).div_euclid(7))
+    }
+
+    /// Returns the day of the week
+    pub fn weekday(&self) -> Result<Weekday> {
+        Ok(Weekday::ALL[self.weekday_index_fast()? as usize])
+    }
+
+    /// Weekday index (0 = Sunday) counted on from the epoch's weekday
+    ///
+    /// Within [`NepaliDate::MIN`]..=[`NepaliDate::MAX`] this uses the ordinal
+    /// only, with no Gregorian conversion. Dates outside that range (reachable
+    /// with the `astronomical` feature) go through Gregorian conversion.
+    pub fn weekday_index_fast(&self) -> Result<u8> {
+        if (NepaliDate::MIN..=NepaliDate::MAX).contains(self) {
+            let days = self.try_to_ordinal()? - 1;
+            return Ok((Self::EPOCH_WEEKDAY.index() as i32 + days).rem_euclid(7) as u8);
+        }
+
+        let (y, m, d) = self.to_gregorian()?;
+        Ok(calculate_weekday(y, m, d) as u8)
+    }
+
+    /// Returns the nearest date after this one falling on `weekday`
+    ///
+    /// The current date is never returned; if it already falls on `weekday`
+    /// the result is one week later.
+    pub fn next_weekday(&self, weekday: Weekday) -> Result<Self> {
+        let current = self.weekday()?.index() as i32;
+        let days = (weekday.index() as i32 - current + 6).rem_euclid(7) + 1;
+        self.add_days(days)
+    }
+
+    /// Returns the nearest date before this one falling on `weekday`
+    ///
+    /// The current date is never returned; if it already falls on `weekday`
+    /// the result is one week earlier.
+    pub fn previous_weekday(&self, weekday: Weekday) -> Result<Self> {
+        let current = self.weekday()?.index() as i32;
+        let days = (current - weekday.index() as i32 + 6).rem_euclid(7) + 1;
+        self.add_days(-days)
+    }
+
+    /// Counts working days from this date up to (excluding) `other`
+    ///
+    /// Saturdays and any date in `holidays` are not counted. Like
+    /// [`NepaliDate::days_between`] the result is negative when `other` is
+    /// earlier, covering `other..self` instead.
+    pub fn working_days_between(&self, other: &NepaliDate, holidays: &[NepaliDate]) -> Result<i64> {
+        let (start, end, sign) = if self <= other {
+            (*self, *other, 1)
+        } else {
+            (*other, *self, -1)
+        };
+
+        let days = start.days_between(&end)?;
+        let first = start.weekday()?.index() as i64;
+        let count = NepaliDateRange::new(start, end)
+            .take(days as usize)
+            .enumerate()
+            .filter(|&(offset, date)| {
+                (first + offset as i64) % 7 != Weekday::Saturday.index() as i64
+                    && !holidays.contains(&date)
+            })
+            .count() as i64;
+
+        Ok(sign * count)
+    }
+
+    /// Moves `n` working days forward (or backward if negative), skipping Saturdays and `holidays`
+    ///
+    /// The result is always a working day unless `n` is 0, in which case the
+    /// date is returned unchanged.
+    pub fn add_working_days(&self, n: i32, holidays: &[NepaliDate]) -> Result<Self> {
+        let step = n.signum();
+        let mut date = *self;
+        let mut weekday = self.weekday()?.index() as i32;
+        let mut remaining = n.abs();
+
+        while remaining > 0 {
+            date = date.add_days(step)?;
+            weekday = (weekday + step).rem_euclid(7);
+            if weekday != Weekday::Saturday.index() as i32 && !holidays.contains(&date) {
+                remaining -= 1;
+            }
+        }
+
+        Ok(date)
+    }
+
+    /// Returns this date if it falls on `weekday`, otherwise the next one that does
+    pub fn next_occurrence_including_today(&self, weekday: Weekday) -> Result<Self> {
+        if self.weekday()? == weekday {
+            Ok(*self)
+        } else {
+            self.next_weekday(weekday)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::date::BS_EPOCH_AD;
+
+    #[test]
+    fn test_epoch_weekday_matches_zeller() {
+        let (y, m, d) = BS_EPOCH_AD;
+        assert_eq!(
+            Weekday::ALL[calculate_weekday(y, m, d)],
+            NepaliDate::EPOCH_WEEKDAY
+        );
+    }
+
+    #[cfg(any(feature = "lookup-tables", feature = "astronomical"))]
+    #[test]
+    fn test_fast_weekday_matches_zeller() {
+        let (y, m, d) = NepaliDate::MIN.to_gregorian().unwrap();
+        assert_eq!(
+            Weekday::ALL[calculate_weekday(y, m, d)],
+            NepaliDate::EPOCH_WEEKDAY
+        );
+
+        // Every 97th day across the table, a stride coprime to 7
+        let mut ordinal = 1;
+        while ordinal <= NepaliDate::MAX.to_ordinal() {
+            let date = NepaliDate::from_ordinal(ordinal).unwrap();
+            let (y, m, d) = date.to_gregorian().unwrap();
+            assert_eq!(
+                date.weekday_index_fast().unwrap() as usize,
+                calculate_weekday(y, m, d),
+                "{}",
+                date
+            );
+            ordinal += 97;
+        }
+
+        assert!(
+            NepaliDate::from_ymd_unchecked(2081, 1, 32)
+                .weekday_index_fast()
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn test_index_round_trip() {
+        for (i, weekday) in Weekday::ALL.iter().enumerate() {
+            assert_eq!(Weekday::from_index(i as u8), Some(*weekday));
+            assert_eq!(weekday.index() as usize, i);
+        }
+        assert_eq!(Weekday::from_index(7), None);
+        assert_eq!(Weekday::Sunday.nepali_name(), "Aaitabaar");
+    }
+
+    #[cfg(any(feature = "lookup-tables", feature = "astronomical"))]
+    #[test]
+    fn test_working_days_over_two_weeks() {
+        // 2081-01-01 and 2081-01-08 are Saturdays
+        let start = NepaliDate::new(2081, 1, 1).unwrap();
+        let end = NepaliDate::new(2081, 1, 15).unwrap();
+        assert_eq!(start.working_days_between(&end, &[]).unwrap(), 12);
+        assert_eq!(end.working_days_between(&start, &[]).unwrap(), -12);
+
+        // Loktantra Diwas falls on a Tuesday; a holiday on a Saturday changes nothing
+        let holidays = [
+            NepaliDate::new(2081, 1, 11).unwrap(),
+            NepaliDate::new(2081, 1, 8).unwrap(),
+        ];
+        assert_eq!(start.working_days_between(&end, &holidays).unwrap(), 11);
+
+        let monday = NepaliDate::new(2081, 1, 3).unwrap();
+        assert_eq!(
+            monday.add_working_days(5, &[]).unwrap(),
+            NepaliDate::new(2081, 1, 9).unwrap()
+        );
+        assert_eq!(
+            monday.add_working_days(7, &holidays).unwrap(),
+            NepaliDate::new(2081, 1, 12).unwrap()
+        );
+        assert_eq!(
+            NepaliDate::new(2081, 1, 9)
+                .unwrap()
+                .add_working_days(-5, &[])
+                .unwrap(),
+            monday
+        );
+        assert_eq!(start.add_working_days(0, &holidays).unwrap(), start);
+    }
+
+    #[cfg(any(feature = "lookup-tables", feature = "astronomical"))]
+    #[test]
+    fn test_weekday() {
+        // 2077-05-19 BS is Friday, 2020-09-04 AD
+        let date = NepaliDate::new(2077, 5, 19).unwrap();
+        assert_eq!(date.weekday().unwrap(), Weekday::Friday);
+    }
+
+    #[cfg(any(feature = "lookup-tables", feature = "astronomical"))]
+    #[test]
+    fn test_year_start_weekday_matches_month_calendar() {
+        for year in [2077, 2080, 2081] {
+            let baisakh = NepaliDate::new(year, 1, 1).unwrap().month_calendar();
+            let first_row = baisakh.lines().nth(2).unwrap();
+            let offset = (first_row.len() - first_row.trim_start().len()) / 3;
+
+            let weekday = NepaliDate::year_start_weekday(year).unwrap();
+            assert_eq!(weekday.index() as usize, offset, "{}", year);
+        }
+    }
+
+    #[cfg(any(feature = "lookup-tables", feature = "astronomical"))]
+    #[test]
+    fn test_ordinal_week_buckets() {
+        assert_eq!(NepaliDate::MIN.ordinal_week().unwrap(), 0);
+        assert_eq!(
+            NepaliDate::MIN.add_days(1).unwrap().ordinal_week().unwrap(),
+            1
+        );
+
+        // 2081-01-02 is a Sunday; the week runs to the following Saturday
+        let sunday = NepaliDate::new(2081, 1, 2).unwrap();
+        assert_eq!(sunday.weekday().unwrap(), Weekday::Sunday);
+        let week = sunday.ordinal_week().unwrap();
+        for offset in 1..7 {
+            assert_eq!(
+                sunday.add_days(offset).unwrap().ordinal_week().unwrap(),
+                week
+            );
+        }
+        assert_eq!(
+            sunday.add_days(7).unwrap().ordinal_week().unwrap(),
+            week + 1
+        );
+        assert_eq!(
+            sunday.add_days(-1).unwrap().ordinal_week().unwrap(),
+            week - 1
+        );
+    }
+
+    #[cfg(any(feature = "lookup-tables", feature = "astronomical"))]
+    #[test]
+    fn test_week_of_year() {
+        // 2081-01-01 BS is a Saturday, so the next day starts week 2
+        let start = NepaliDate::new(2081, 1, 1).unwrap();
+        assert_eq!(start.weekday().unwrap(), Weekday::Saturday);
+        assert_eq!(start.week_of_year().unwrap(), 1);
+        assert_eq!(start.add_days(1).unwrap().week_of_year().unwrap(), 2);
+        assert_eq!(start.add_days(7).unwrap().week_of_year().unwrap(), 2);
+        assert_eq!(start.add_days(8).unwrap().week_of_year().unwrap(), 3);
+    }
+
+    #[cfg(any(feature = "lookup-tables", feature = "astronomical"))]
+    #[test]
+    fn test_weekday_navigation_on_same_day() {
+        let friday = NepaliDate::new(2077, 5, 19).unwrap();
+
+        assert_eq!(
+            friday.next_weekday(Weekday::Friday).unwrap(),
+            NepaliDate::new(2077, 5, 26).unwrap()
+        );
+        assert_eq!(
+            friday.previous_weekday(Weekday::Friday).unwrap(),
+            NepaliDate::new(2077, 5, 12).unwrap()
+        );
+        assert_eq!(
+            friday
+                .next_occurrence_including_today(Weekday::Friday)
+                .unwrap(),
+            friday
+        );
+    }
+
+    #[cfg(any(feature = "lookup-tables", feature = "astronomical"))]
+    #[test]
+    fn test_weekday_navigation_other_day() {
+        let friday = NepaliDate::new(2077, 5, 19).unwrap();
+
+        assert_eq!(
+            friday.next_weekday(Weekday::Sunday).unwrap(),
+            NepaliDate::new(2077, 5, 21).unwrap()
+        );
+        assert_eq!(
+            friday.previous_weekday(Weekday::Saturday).unwrap(),
+            NepaliDate::new(2077, 5, 13).unwrap()
+        );
+        assert_eq!(
+            friday
+                .next_occurrence_including_today(Weekday::Saturday)
+                .unwrap(),
+            NepaliDate::new(2077, 5, 20).unwrap()
+        );
+    }
+}
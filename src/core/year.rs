@@ -0,0 +1,84 @@
+//! Whole-year views of the Nepali calendar for calendar widgets
+
+use crate::core::date::NepaliDate;
+use crate::core::error::Result;
+use crate::core::weekday::Weekday;
+
+#[cfg(feature = "astronomical")]
+use crate::astronomical::lunar::{Tithi, TithiCalculator};
+
+/// A single day of a calendar grid with its display metadata
+#[derive(Debug, Clone, Copy)]
+pub struct DayInfo {
+    pub date: NepaliDate,
+    pub weekday: Weekday,
+    /// Saturday is the weekly public holiday in Nepal
+    pub is_saturday: bool,
+    /// Tithi prevailing at sunrise (approximated as 06:00 NPT)
+    #[cfg(feature = "astronomical")]
+    pub tithi: Tithi,
+}
+
+impl NepaliDate {
+    /// Returns every day of a BS year, in order, annotated with its weekday
+    ///
+    /// Only Baisakh 1 is converted to Gregorian; the remaining weekdays are
+    /// derived by counting forward. With the `astronomical` feature each day
+    /// also carries its sunrise Tithi.
+    ///
+    /// # Examples:
+    /// ```
+    /// # use npdatetime::NepaliDate;
+    /// # if cfg!(any(feature = "lookup-tables", feature = "astronomical")) {
+    /// let days = NepaliDate::year_days(2077).unwrap();
+    /// assert_eq!(days.len(), NepaliDate::days_in_year(2077).unwrap() as usize);
+    /// assert_eq!(days[0].date, NepaliDate::new(2077, 1, 1).unwrap());
+    /// # }
+    /// ```
+    pub fn year_days(year: i32) -> Result<Vec<DayInfo>> {
+        let first = NepaliDate::new(year, 1, 1)?;
+        let first_weekday = first.weekday()?.index() as usize;
+        #[cfg(feature = "astronomical")]
+        let first_jd = first.to_julian_day()?;
+
+        let mut days = Vec::with_capacity(Self::days_in_year(year)? as usize);
+        for month in 1..=12 {
+            for day in 1..=Self::days_in_month(year, month)? {
+                let offset = days.len();
+                let weekday = Weekday::ALL[(first_weekday + offset) % 7];
+                days.push(DayInfo {
+                    date: NepaliDate { year, month, day },
+                    weekday,
+                    is_saturday: weekday == Weekday::Saturday,
+                    #[cfg(feature = "astronomical")]
+                    tithi: TithiCalculator::tithi_at_sunrise(first_jd.add_days(offset as f64)),
+                });
+            }
+        }
+
+        Ok(days)
+    }
+}
+
+#[cfg(test)]
+#[cfg(any(feature = "lookup-tables", feature = "astronomical"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_year_days() {
+        let days = NepaliDate::year_days(2080).unwrap();
+        assert_eq!(days.len(), NepaliDate::days_in_year(2080).unwrap() as usize);
+        assert_eq!(days[0].date, NepaliDate::new(2080, 1, 1).unwrap());
+        assert_eq!(days[0].weekday, days[0].date.weekday().unwrap());
+
+        for pair in days.windows(2) {
+            assert_eq!(pair[1].weekday.index(), (pair[0].weekday.index() + 1) % 7);
+            assert_eq!(pair[1].date, pair[0].date.add_days(1).unwrap());
+        }
+
+        let last = days.last().unwrap();
+        assert_eq!(last.weekday, last.date.weekday().unwrap());
+        assert_eq!(last.is_saturday, last.weekday == Weekday::Saturday);
+    }
+}
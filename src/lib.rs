@@ -14,6 +14,7 @@
 //! - `lookup-tables` (default): Enables CSV-backed pre-calculated calendar data (1975-2100 BS).
 //! - `astronomical`: Enables full solar and lunar position calculations for any date range.
 //! - `std`: Enables standard library features including `Chrono` integration.
+//! - `time`: Enables conversions to and from `time::Date`.
 //! - `wasm`: Enables WASM bindings for web usage.
 //!
 
@@ -25,16 +26,86 @@ pub mod lookup;
 pub mod astronomical;
 
 pub use core::date::NepaliDate;
+pub use core::datetime::NepaliDateTime;
 pub use core::error::{NpdatetimeError, Result};
+pub use core::interval::NepaliDateInterval;
+pub use core::month::NepaliMonth;
+pub use core::range::NepaliDateRange;
+pub use core::weekday::Weekday;
 
 /// Prelude for common imports
+///
+/// # Examples:
+/// ```
+/// use npdatetime::prelude::*;
+///
+/// fn saturdays_in(year: i32, month: u8) -> Result<Vec<NepaliDate>> {
+///     let interval = NepaliDateInterval::new(
+///         NepaliDate::new(year, month, 1)?,
+///         NepaliDate::new(year, month, NepaliDate::days_in_month(year, month)?)?,
+///     )?;
+///     let mut saturdays = Vec::new();
+///     for date in NepaliDateRange::new(interval.start, interval.end) {
+///         if date.weekday()? == Weekday::Saturday {
+///             saturdays.push(date);
+///         }
+///     }
+///     Ok(saturdays)
+/// }
+///
+/// # if cfg!(any(feature = "lookup-tables", feature = "astronomical")) {
+/// let month = NepaliMonth::Bhadra;
+/// let saturdays = saturdays_in(2077, month.number()).unwrap();
+/// assert_eq!(saturdays.len(), 4);
+/// assert!(matches!(
+///     NepaliDate::new(2077, 13, 1),
+///     Err(NpdatetimeError::InvalidDate(_))
+/// ));
+/// # }
+/// ```
 pub mod prelude {
     pub use crate::core::date::NepaliDate;
     pub use crate::core::error::{NpdatetimeError, Result};
+    pub use crate::core::interval::NepaliDateInterval;
+    pub use crate::core::month::NepaliMonth;
+    pub use crate::core::range::NepaliDateRange;
+    pub use crate::core::weekday::Weekday;
 
     #[cfg(feature = "astronomical")]
     pub use crate::astronomical::{AstronomicalCalendar, SankrantiFinder, TithiCalculator};
 }
 
+/// Calendar sources and platform support compiled into this build
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Capabilities {
+    /// Embedded lookup tables (`lookup-tables` feature)
+    pub lookup: bool,
+    /// Astronomical calculation for any year (`astronomical` feature)
+    pub astronomical: bool,
+    /// Standard library integration (`std` feature)
+    pub std: bool,
+}
+
+/// Reports which features this build of the library was compiled with
+///
+/// Together with [`lookup::supported_range`] this lets a UI decide which
+/// dates it can offer without trying a conversion first.
+///
+/// # Examples:
+/// ```
+/// let caps = npdatetime::capabilities();
+/// if !caps.astronomical {
+///     // Only the lookup range is convertible
+/// }
+/// assert_eq!(caps.lookup, cfg!(feature = "lookup-tables"));
+/// ```
+pub fn capabilities() -> Capabilities {
+    Capabilities {
+        lookup: cfg!(feature = "lookup-tables"),
+        astronomical: cfg!(feature = "astronomical"),
+        std: cfg!(feature = "std"),
+    }
+}
+
 /// Library version
 pub const VERSION: &str = env!("CARGO_PKG_VERSION");
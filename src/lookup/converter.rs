@@ -1 +1,358 @@
-// BS ↔ AD conversion
+//! BS ↔ AD conversion backed by precomputed cumulative offsets
+//!
+//! [`NepaliCalendar`] walks the lookup table once on construction so that
+//! every subsequent conversion is a binary search plus at most 12 month steps,
+//! instead of re-summing every year since the epoch.
+
+use super::CalendarTable;
+use crate::core::date::{
+    BS_EPOCH_AD, NepaliDate, days_to_gregorian, gregorian_to_days, validate_gregorian,
+};
+use crate::core::error::{NpdatetimeError, Result};
+
+/// Cached calendar for bulk conversions
+///
+/// The free functions on [`NepaliDate`] remain the simplest entry point; this
+/// type is meant for servers converting many dates in a loop.
+///
+/// # Examples:
+/// ```
+/// use npdatetime::lookup::NepaliCalendar;
+///
+/// let cal = NepaliCalendar::new();
+/// let date = cal.from_gregorian(2020, 9, 4).unwrap();
+/// assert_eq!(date.to_string(), "2077-05-19");
+/// assert_eq!(cal.to_gregorian(&date).unwrap(), (2020, 9, 4));
+/// ```
+#[derive(Debug, Clone)]
+pub struct NepaliCalendar {
+    base_year: i32,
+    /// Day number (as in `gregorian_to_days`) of Baisakh 1 of `base_year`
+    epoch_days: i64,
+    month_data: Vec<[u8; 12]>,
+    /// Days from the epoch to Baisakh 1 of each year, plus one trailing total
+    year_offsets: Vec<i64>,
+}
+
+impl NepaliCalendar {
+    /// Builds the cache from the embedded lookup table
+    pub fn new() -> Self {
+        Self::from_table(CalendarTable::builtin())
+    }
+
+    /// Builds the cache from a custom table, using its base year and Gregorian anchor as the epoch
+    ///
+    /// This is how dates before 1975 BS are reached: supply a table that
+    /// starts earlier, and every conversion on the returned calendar uses it.
+    pub fn from_table(table: CalendarTable) -> Self {
+        let (ey, em, ed) = table.epoch_ad();
+        let base_year = table.base_year();
+        let month_data = table.months().to_vec();
+
+        let mut year_offsets = Vec::with_capacity(month_data.len() + 1);
+        let mut total = 0i64;
+        year_offsets.push(total);
+        for months in &month_data {
+            total += months.iter().map(|&d| d as i64).sum::<i64>();
+            year_offsets.push(total);
+        }
+
+        NepaliCalendar {
+            base_year,
+            epoch_days: gregorian_to_days(ey, em, ed),
+            month_data,
+            year_offsets,
+        }
+    }
+
+    fn year_index(&self, year: i32) -> Result<usize> {
+        if year < self.base_year {
+            return Err(NpdatetimeError::BeforeEpoch(format!(
+                "Year {} is before the BS epoch {}",
+                year, self.base_year
+            )));
+        }
+        if year >= self.base_year + self.month_data.len() as i32 {
+            return Err(NpdatetimeError::AfterSupportedRange(format!(
+                "Year {} is out of supported range",
+                year
+            )));
+        }
+        Ok((year - self.base_year) as usize)
+    }
+
+    /// Returns the number of days in a given month
+    pub fn days_in_month(&self, year: i32, month: u8) -> Result<u8> {
+        if !(1..=12).contains(&month) {
+            return Err(NpdatetimeError::InvalidDate(format!(
+                "Invalid month: {}",
+                month
+            )));
+        }
+        let index = self.year_index(year)?;
+        Ok(self.month_data[index][(month - 1) as usize])
+    }
+
+    /// Returns the number of days in a given year
+    pub fn days_in_year(&self, year: i32) -> Result<u16> {
+        let index = self.year_index(year)?;
+        Ok((self.year_offsets[index + 1] - self.year_offsets[index]) as u16)
+    }
+
+    /// Converts a Nepali date to Gregorian date (year, month, day)
+    ///
+    /// The month and day are checked against this calendar's table, since
+    /// `NepaliDate` fields can be set directly.
+    pub fn to_gregorian(&self, date: &NepaliDate) -> Result<(i32, u8, u8)> {
+        let max_day = self.days_in_month(date.year, date.month)?;
+        if date.day < 1 || date.day > max_day {
+            return Err(NpdatetimeError::InvalidDate(format!(
+                "Day must be between 1 and {}, got {}",
+                max_day, date.day
+            )));
+        }
+
+        let index = self.year_index(date.year)?;
+        let months = &self.month_data[index];
+
+        let total_days = self.year_offsets[index]
+            + months[..(date.month - 1) as usize]
+                .iter()
+                .map(|&d| d as i64)
+                .sum::<i64>()
+            + (date.day - 1) as i64;
+
+        Ok(days_to_gregorian(self.epoch_days + total_days))
+    }
+
+    /// Days since 1975-01-01 BS, numbered like [`NepaliDate::to_ordinal`] (the epoch is 1)
+    ///
+    /// Dates before 1975 BS, available with a table that starts earlier,
+    /// have ordinals of 0 or below.
+    pub fn to_ordinal(&self, date: &NepaliDate) -> Result<i32> {
+        let (y, m, d) = self.to_gregorian(date)?;
+        Ok((gregorian_to_days(y, m, d) - bs_epoch_days() + 1) as i32)
+    }
+
+    /// Creates a Nepali date from an ordinal numbered like [`NepaliDate::from_ordinal`]
+    ///
+    /// Ordinals of 0 or below reach years before 1975 BS when this
+    /// calendar's table covers them, and fail with `BeforeEpoch` otherwise.
+    pub fn from_ordinal(&self, ordinal: i32) -> Result<NepaliDate> {
+        let (y, m, d) = days_to_gregorian(bs_epoch_days() + ordinal as i64 - 1);
+        self.from_gregorian(y, m, d)
+    }
+
+    /// Creates a Nepali date from a Gregorian date
+    pub fn from_gregorian(&self, year: i32, month: u8, day: u8) -> Result<NepaliDate> {
+        validate_gregorian(year, month, day)?;
+        let mut remaining_days = gregorian_to_days(year, month, day) - self.epoch_days;
+        let total = *self.year_offsets.last().unwrap_or(&0);
+
+        if remaining_days < 0 {
+            return Err(NpdatetimeError::BeforeEpoch(
+                "Date is before the BS epoch".to_string(),
+            ));
+        }
+        if remaining_days >= total {
+            return Err(NpdatetimeError::AfterSupportedRange(format!(
+                "Date {}-{:02}-{:02} is after the supported range",
+                year, month, day
+            )));
+        }
+
+        // Index of the last year starting on or before `remaining_days`
+        let index = self.year_offsets.partition_point(|&o| o <= remaining_days) - 1;
+        remaining_days -= self.year_offsets[index];
+
+        let mut bs_month = 1u8;
+        for &month_days in &self.month_data[index] {
+            if remaining_days < month_days as i64 {
+                break;
+            }
+            remaining_days -= month_days as i64;
+            bs_month += 1;
+        }
+
+        Ok(NepaliDate {
+            year: self.base_year + index as i32,
+            month: bs_month,
+            day: (remaining_days + 1) as u8,
+        })
+    }
+}
+
+/// Day number (as in `gregorian_to_days`) of 1975-01-01 BS, ordinal 1
+fn bs_epoch_days() -> i64 {
+    let (y, m, d) = BS_EPOCH_AD;
+    gregorian_to_days(y, m, d)
+}
+
+impl Default for NepaliCalendar {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_matches_uncached_conversion() {
+        let cal = NepaliCalendar::new();
+        for year in (1975..=2100).step_by(5) {
+            for month in 1..=12 {
+                let date = NepaliDate::new(year, month, 1).unwrap();
+                let greg = date.to_gregorian().unwrap();
+                assert_eq!(cal.to_gregorian(&date).unwrap(), greg);
+                assert_eq!(cal.from_gregorian(greg.0, greg.1, greg.2).unwrap(), date);
+            }
+        }
+    }
+
+    #[test]
+    fn test_days_in_month_and_year() {
+        let cal = NepaliCalendar::new();
+        assert_eq!(cal.days_in_month(2077, 2).unwrap(), 32);
+        let total: u16 = (1..=12)
+            .map(|m| NepaliDate::days_in_month(2077, m).unwrap() as u16)
+            .sum();
+        assert_eq!(cal.days_in_year(2077).unwrap(), total);
+        assert!(cal.days_in_month(2077, 13).is_err());
+        assert!(cal.days_in_year(2101).is_err());
+    }
+
+    #[test]
+    fn test_range_edges() {
+        let cal = NepaliCalendar::new();
+        assert_eq!(
+            cal.from_gregorian(1918, 4, 13).unwrap(),
+            NepaliDate::new(1975, 1, 1).unwrap()
+        );
+        assert!(cal.from_gregorian(1918, 4, 12).is_err());
+
+        let last = NepaliDate::new(2100, 12, 30).unwrap();
+        let (y, m, d) = cal.to_gregorian(&last).unwrap();
+        assert_eq!(cal.from_gregorian(y, m, d).unwrap(), last);
+        let (ny, nm, nd) = days_to_gregorian(gregorian_to_days(y, m, d) + 1);
+        assert!(cal.from_gregorian(ny, nm, nd).is_err());
+
+        assert!(matches!(
+            cal.from_gregorian(2020, 2, 30),
+            Err(NpdatetimeError::InvalidDate(_))
+        ));
+    }
+
+    #[test]
+    fn test_to_gregorian_rejects_invalid_components() {
+        let cal = NepaliCalendar::new();
+        for (month, day) in [(0, 1), (13, 1), (5, 0), (5, 32)] {
+            let date = NepaliDate {
+                year: 2077,
+                month,
+                day,
+            };
+            assert!(
+                matches!(
+                    cal.to_gregorian(&date),
+                    Err(NpdatetimeError::InvalidDate(_))
+                ),
+                "{}-{}",
+                month,
+                day
+            );
+        }
+        let last = NepaliDate {
+            year: 2077,
+            month: 5,
+            day: 31,
+        };
+        assert!(cal.to_gregorian(&last).is_ok());
+    }
+
+    /// The embedded table with five extra years ahead of it, anchored so
+    /// 1975 still starts on 1918-04-13
+    fn extended_calendar() -> (NepaliCalendar, (i32, u8, u8)) {
+        let prefix = vec![
+            [31, 31, 32, 31, 31, 31, 30, 29, 30, 29, 30, 30],
+            [31, 31, 32, 31, 32, 30, 30, 29, 30, 29, 30, 30],
+            [31, 32, 31, 32, 31, 30, 30, 30, 29, 29, 30, 31],
+            [30, 32, 31, 32, 31, 30, 30, 30, 29, 30, 29, 31],
+            [31, 31, 32, 31, 31, 31, 30, 29, 30, 29, 30, 30],
+        ];
+        let prefix_days: i64 = prefix.iter().flatten().map(|&d| d as i64).sum();
+        let (ey, em, ed) = crate::core::date::BS_EPOCH_AD;
+        let epoch = days_to_gregorian(gregorian_to_days(ey, em, ed) - prefix_days);
+
+        assert_eq!(epoch, (1913, 4, 13));
+
+        let mut months = prefix;
+        months.extend_from_slice(CalendarTable::builtin().months());
+        let cal = NepaliCalendar::from_table(CalendarTable::new(1970, epoch, months).unwrap());
+        (cal, epoch)
+    }
+
+    #[test]
+    fn test_table_with_earlier_epoch() {
+        let (cal, epoch) = extended_calendar();
+        let first = cal.from_gregorian(epoch.0, epoch.1, epoch.2).unwrap();
+        assert_eq!((first.year, first.month, first.day), (1970, 1, 1));
+
+        let date = NepaliDate {
+            year: 1970,
+            month: 5,
+            day: 12,
+        };
+        let (y, m, d) = cal.to_gregorian(&date).unwrap();
+        assert_eq!(cal.from_gregorian(y, m, d).unwrap(), date);
+        assert_eq!(cal.days_in_month(1970, 3).unwrap(), 32);
+
+        // The embedded range converts exactly as before
+        assert_eq!(
+            cal.from_gregorian(1918, 4, 13).unwrap(),
+            NepaliDate::new(1975, 1, 1).unwrap()
+        );
+        assert!(cal.from_gregorian(epoch.0, epoch.1, epoch.2 - 1).is_err());
+        assert!(NepaliCalendar::new().from_gregorian(y, m, d).is_err());
+    }
+
+    #[test]
+    fn test_ordinals_around_epoch() {
+        let builtin = NepaliCalendar::new();
+        let (extended, _) = extended_calendar();
+        let epoch = NepaliDate::new(1975, 1, 1).unwrap();
+
+        for cal in [&builtin, &extended] {
+            assert_eq!(cal.from_ordinal(1).unwrap(), epoch);
+            assert_eq!(cal.to_ordinal(&epoch).unwrap(), 1);
+
+            let later = NepaliDate::new(2077, 5, 19).unwrap();
+            assert_eq!(cal.to_ordinal(&later).unwrap(), later.to_ordinal());
+            assert_eq!(cal.from_ordinal(later.to_ordinal()).unwrap(), later);
+        }
+
+        assert!(matches!(
+            builtin.from_ordinal(0),
+            Err(NpdatetimeError::BeforeEpoch(_))
+        ));
+        assert!(matches!(
+            builtin.from_ordinal(-100),
+            Err(NpdatetimeError::BeforeEpoch(_))
+        ));
+
+        // 1974 Chaitra has 30 days in the extended table
+        let last_of_1974 = extended.from_ordinal(0).unwrap();
+        assert_eq!(
+            (last_of_1974.year, last_of_1974.month, last_of_1974.day),
+            (1974, 12, 30)
+        );
+        let first_of_1970 = extended.from_ordinal(-1825).unwrap();
+        assert_eq!(
+            (first_of_1970.year, first_of_1970.month, first_of_1970.day),
+            (1970, 1, 1)
+        );
+        assert_eq!(extended.to_ordinal(&first_of_1970).unwrap(), -1825);
+        assert!(extended.from_ordinal(-1826).is_err());
+    }
+}
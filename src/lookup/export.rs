@@ -0,0 +1,280 @@
+//! Exporting a BS year for spreadsheets and calendar applications
+
+use crate::core::date::{NepaliDate, days_to_gregorian, gregorian_to_days};
+use crate::core::error::{NpdatetimeError, Result};
+use crate::core::festival::festivals_in_year;
+use crate::core::range::NepaliDateRange;
+
+/// An all-day calendar entry
+struct Event {
+    date: NepaliDate,
+    ad: (i32, u8, u8),
+    name: String,
+}
+
+/// Exports the festivals of a BS year as an RFC 5545 iCalendar document
+///
+/// Each festival becomes an all-day `VEVENT` whose summary carries the BS
+/// date, e.g. `Nepali New Year (2081-01-01 BS)`. With the `astronomical`
+/// feature the Sankrantis of the year are added as well, except those
+/// already observed as a festival. Lines end in CRLF and are folded at 75
+/// octets as the RFC requires. `DTSTAMP` is the current UTC time; use
+/// [`export_year_ics_at`] for reproducible output.
+///
+/// # Examples:
+/// ```
+/// let ics = npdatetime::lookup::export_year_ics(2081).unwrap();
+/// assert!(ics.starts_with("BEGIN:VCALENDAR\r\n"));
+/// assert!(ics.contains("SUMMARY:Nepali New Year (2081-01-01 BS)\r\n"));
+/// ```
+pub fn export_year_ics(year: i32) -> Result<String> {
+    use std::time::{SystemTime, UNIX_EPOCH};
+
+    let duration = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_err(|e| NpdatetimeError::CalculationError(format!("System clock error: {}", e)))?;
+
+    export_year_ics_at(year, duration.as_secs() as i64)
+}
+
+/// Like [`export_year_ics`], stamping each event with `timestamp` (Unix
+/// seconds, UTC) as its `DTSTAMP`
+///
+/// # Examples:
+/// ```
+/// let ics = npdatetime::lookup::export_year_ics_at(2081, 1_700_000_000).unwrap();
+/// assert!(ics.contains("DTSTAMP:20231114T221320Z\r\n"));
+/// ```
+pub fn export_year_ics_at(year: i32, timestamp: i64) -> Result<String> {
+    let mut events = Vec::new();
+    for festival in festivals_in_year(year)? {
+        events.push(Event {
+            date: festival.date,
+            ad: festival.date.to_gregorian()?,
+            name: festival.name.to_string(),
+        });
+    }
+
+    #[cfg(feature = "astronomical")]
+    {
+        use crate::astronomical::SankrantiFinder;
+
+        for sankranti in SankrantiFinder::find_all_in_year(year)
+            .map_err(NpdatetimeError::CalculationError)?
+            .into_iter()
+            .filter(|s| s.festival_name().is_none())
+        {
+            let (y, m, d, _) = sankranti.local_datetime_npt();
+            events.push(Event {
+                date: NepaliDate::from_gregorian(y, m, d)?,
+                ad: (y, m, d),
+                name: format!("{} Sankranti", sankranti.sign_name()),
+            });
+        }
+        events.sort_by_key(|event| event.date);
+    }
+
+    let dtstamp = {
+        let (y, m, d) =
+            days_to_gregorian(gregorian_to_days(1970, 1, 1) + timestamp.div_euclid(86400));
+        let secs = timestamp.rem_euclid(86400);
+        format!(
+            "{:04}{:02}{:02}T{:02}{:02}{:02}Z",
+            y,
+            m,
+            d,
+            secs / 3600,
+            secs / 60 % 60,
+            secs % 60
+        )
+    };
+
+    let mut ics = String::new();
+    let mut line = |text: &str| fold_line(&mut ics, text);
+
+    line("BEGIN:VCALENDAR");
+    line("VERSION:2.0");
+    line("PRODID:-//npdatetime//Nepali Calendar//EN");
+    line("CALSCALE:GREGORIAN");
+    for event in &events {
+        let (y, m, d) = event.ad;
+        let next = days_to_gregorian(gregorian_to_days(y, m, d) + 1);
+        let slug: String = event
+            .name
+            .chars()
+            .map(|c| {
+                if c.is_ascii_alphanumeric() {
+                    c.to_ascii_lowercase()
+                } else {
+                    '-'
+                }
+            })
+            .collect();
+
+        line("BEGIN:VEVENT");
+        line(&format!("UID:{}-{}@npdatetime", event.date, slug));
+        line(&format!("DTSTAMP:{}", dtstamp));
+        line(&format!("DTSTART;VALUE=DATE:{:04}{:02}{:02}", y, m, d));
+        line(&format!(
+            "DTEND;VALUE=DATE:{:04}{:02}{:02}",
+            next.0, next.1, next.2
+        ));
+        line(&format!(
+            "SUMMARY:{}",
+            escape_text(&format!("{} ({} BS)", event.name, event.date))
+        ));
+        line("END:VEVENT");
+    }
+    line("END:VCALENDAR");
+
+    Ok(ics)
+}
+
+/// Exports every day of a BS year as CSV
+///
+/// Columns are `bs_date,ad_date,weekday`, plus `tithi` (the Tithi at
+/// sunrise) with the `astronomical` feature.
+///
+/// # Examples:
+/// ```
+/// let csv = npdatetime::lookup::export_year_csv(2081).unwrap();
+/// let mut lines = csv.lines();
+/// assert!(lines.next().unwrap().starts_with("bs_date,ad_date,weekday"));
+/// assert!(lines.next().unwrap().starts_with("2081-01-01,2024-04-13,Saturday"));
+/// ```
+pub fn export_year_csv(year: i32) -> Result<String> {
+    let first = NepaliDate::new(year, 1, 1)?;
+    let last = NepaliDate::new(year, 12, NepaliDate::days_in_month(year, 12)?)?;
+    let (y, m, d) = first.to_gregorian()?;
+    let first_day = gregorian_to_days(y, m, d);
+
+    let mut csv = String::from("bs_date,ad_date,weekday");
+    if cfg!(feature = "astronomical") {
+        csv.push_str(",tithi");
+    }
+    csv.push('\n');
+
+    for (offset, date) in NepaliDateRange::new(first, last).enumerate() {
+        let (y, m, d) = days_to_gregorian(first_day + offset as i64);
+        csv.push_str(&format!(
+            "{},{:04}-{:02}-{:02},{}",
+            date,
+            y,
+            m,
+            d,
+            date.weekday()?
+        ));
+
+        #[cfg(feature = "astronomical")]
+        {
+            use crate::astronomical::TithiCalculator;
+            use crate::astronomical::core::JulianDay;
+
+            let tithi = TithiCalculator::tithi_at_sunrise(JulianDay::from_gregorian(y, m, d, 0.0));
+            csv.push(',');
+            csv.push_str(&tithi.full_name());
+        }
+
+        csv.push('\n');
+    }
+
+    Ok(csv)
+}
+
+/// Appends a content line, folded so no physical line exceeds 75 octets
+/// (RFC 5545 section 3.1)
+///
+/// Continuation lines start with a space, and breaks never split a UTF-8
+/// sequence.
+fn fold_line(out: &mut String, text: &str) {
+    const MAX_OCTETS: usize = 75;
+
+    let mut width = 0;
+    for c in text.chars() {
+        if width + c.len_utf8() > MAX_OCTETS {
+            out.push_str("\r\n ");
+            width = 1;
+        }
+        out.push(c);
+        width += c.len_utf8();
+    }
+    out.push_str("\r\n");
+}
+
+/// Escapes iCalendar TEXT values (RFC 5545 section 3.3.11)
+fn escape_text(text: &str) -> String {
+    text.replace('\\', "\\\\")
+        .replace(';', "\\;")
+        .replace(',', "\\,")
+        .replace('\n', "\\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_ics_structure_and_event_count() {
+        let ics = export_year_ics(2081).unwrap();
+        assert!(ics.starts_with("BEGIN:VCALENDAR\r\nVERSION:2.0\r\n"));
+        assert!(ics.ends_with("END:VCALENDAR\r\n"));
+        assert!(ics.lines().all(|l| l.len() <= 75));
+
+        let mut expected = festivals_in_year(2081).unwrap().len();
+        if cfg!(feature = "astronomical") {
+            // Twelve Sankrantis, less the three observed as festivals
+            expected += 9;
+        }
+        assert_eq!(ics.matches("BEGIN:VEVENT\r\n").count(), expected);
+        assert_eq!(ics.matches("END:VEVENT\r\n").count(), expected);
+        assert!(ics.contains("DTSTART;VALUE=DATE:20240413\r\nDTEND;VALUE=DATE:20240414\r\n"));
+    }
+
+    #[test]
+    fn test_ics_dtstamp() {
+        let ics = export_year_ics_at(2081, 1_700_000_000).unwrap();
+        let events = ics.matches("BEGIN:VEVENT\r\n").count();
+        assert_eq!(ics.matches("DTSTAMP:20231114T221320Z\r\n").count(), events);
+        assert_eq!(ics, export_year_ics_at(2081, 1_700_000_000).unwrap());
+    }
+
+    #[test]
+    fn test_fold_line() {
+        let mut out = String::new();
+        fold_line(&mut out, "SUMMARY:short");
+        assert_eq!(out, "SUMMARY:short\r\n");
+
+        let long = format!("SUMMARY:{}", "नेपाली ".repeat(12));
+        let mut out = String::new();
+        fold_line(&mut out, &long);
+        let lines: Vec<&str> = out.split("\r\n").collect();
+        assert!(lines.len() > 2);
+        assert_eq!(lines.last(), Some(&""));
+        assert!(lines.iter().all(|l| l.len() <= 75));
+        assert!(lines[1..lines.len() - 1].iter().all(|l| l.starts_with(' ')));
+
+        // Unfolding (dropping CRLF + space) gives back the original line
+        assert_eq!(out.trim_end_matches("\r\n").replace("\r\n ", ""), long);
+    }
+
+    #[test]
+    fn test_csv_has_a_row_per_day() {
+        let csv = export_year_csv(2081).unwrap();
+        let rows: Vec<&str> = csv.lines().collect();
+        assert_eq!(
+            rows.len(),
+            1 + NepaliDate::days_in_year(2081).unwrap() as usize
+        );
+        assert!(rows[1].starts_with("2081-01-01,2024-04-13,Saturday"));
+        assert!(
+            rows.last()
+                .unwrap()
+                .starts_with("2081-12-31,2025-04-13,Sunday")
+        );
+    }
+
+    #[test]
+    fn test_escape_text() {
+        assert_eq!(escape_text("a,b;c\\d"), "a\\,b\\;c\\\\d");
+    }
+}
@@ -1,7 +1,19 @@
 // Fast lookup table approach
 // Lookup module
 
-use crate::core::date::BS_EPOCH_YEAR;
+pub mod converter;
+pub mod export;
+pub mod table;
+#[cfg(feature = "astronomical")]
+pub mod verify;
+
+pub use converter::NepaliCalendar;
+pub use export::{export_year_csv, export_year_ics, export_year_ics_at};
+pub use table::CalendarTable;
+#[cfg(feature = "astronomical")]
+pub use verify::{Discrepancy, verify_against_astronomical};
+
+use crate::core::date::{BS_EPOCH_YEAR, NepaliDate};
 use crate::core::error::{NpdatetimeError, Result};
 
 // Include data generated by build.rs
@@ -35,9 +47,15 @@ lazy_static::lazy_static! {
 
 /// Returns the number of days in a given BS month using the lookup table
 pub fn get_days_in_month(year: i32, month: u8) -> Result<u8> {
+    if year < BS_EPOCH_YEAR {
+        return Err(NpdatetimeError::BeforeEpoch(format!(
+            "Year {} is before the BS epoch {}",
+            year, BS_EPOCH_YEAR
+        )));
+    }
     let index = (year - BS_EPOCH_YEAR) as usize;
     if index >= BS_MONTH_DATA.len() {
-        return Err(NpdatetimeError::OutOfRange(format!(
+        return Err(NpdatetimeError::AfterSupportedRange(format!(
             "Year {} is out of supported range",
             year
         )));
@@ -46,10 +64,89 @@ pub fn get_days_in_month(year: i32, month: u8) -> Result<u8> {
     Ok(BS_MONTH_DATA[index][(month - 1) as usize])
 }
 
+/// First and last dates covered by the embedded table
+///
+/// # Examples:
+/// ```
+/// use npdatetime::NepaliDate;
+///
+/// let (first, last) = npdatetime::lookup::supported_range();
+/// assert_eq!(first, NepaliDate::MIN);
+/// assert_eq!(last, NepaliDate::MAX);
+/// ```
+pub fn supported_range() -> (NepaliDate, NepaliDate) {
+    let last_year = BS_EPOCH_YEAR + BS_MONTH_DATA.len() as i32 - 1;
+    let last_day = BS_MONTH_DATA.last().map_or(1, |months| months[11]);
+    (
+        NepaliDate::from_ymd_unchecked(BS_EPOCH_YEAR, 1, 1),
+        NepaliDate::from_ymd_unchecked(last_year, 12, last_day),
+    )
+}
+
+/// Nearest date within [`supported_range`], and whether any component had
+/// to be adjusted
+///
+/// Years before or after the table clamp to its first or last date. Within
+/// the table, the month is clamped to 1-12 and the day to that month's
+/// length, like [`NepaliDate::new_clamped`].
+///
+/// # Examples:
+/// ```
+/// use npdatetime::NepaliDate;
+/// use npdatetime::lookup::clamp_to_supported;
+///
+/// assert_eq!(clamp_to_supported(2200, 1, 1), (NepaliDate::MAX, true));
+/// let (date, adjusted) = clamp_to_supported(2081, 2, 40);
+/// assert_eq!((date.to_string(), adjusted), ("2081-02-32".to_string(), true));
+/// ```
+pub fn clamp_to_supported(year: i32, month: u8, day: u8) -> (NepaliDate, bool) {
+    let (first, last) = supported_range();
+    if year < first.year {
+        return (first, true);
+    }
+    if year > last.year {
+        return (last, true);
+    }
+
+    let clamped_month = month.clamp(1, 12);
+    let month_days = BS_MONTH_DATA[(year - BS_EPOCH_YEAR) as usize][(clamped_month - 1) as usize];
+    let clamped_day = day.clamp(1, month_days);
+    (
+        NepaliDate::from_ymd_unchecked(year, clamped_month, clamped_day),
+        (clamped_month, clamped_day) != (month, day),
+    )
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_supported_range_matches_table() {
+        let (first, last) = supported_range();
+        assert_eq!((first.year, first.month, first.day), (1975, 1, 1));
+        assert_eq!(last.year, 1975 + BS_MONTH_DATA.len() as i32 - 1);
+        assert_eq!(last.day, get_days_in_month(last.year, 12).unwrap());
+        assert!(NepaliDate::new(last.year, 12, last.day).is_ok());
+        assert!(get_days_in_month(last.year + 1, 1).is_err());
+    }
+
+    #[test]
+    fn test_clamp_to_supported() {
+        let (first, last) = supported_range();
+        assert_eq!(clamp_to_supported(2200, 5, 10), (last, true));
+        assert_eq!(clamp_to_supported(1900, 5, 10), (first, true));
+        assert_eq!(clamp_to_supported(2100, 13, 40), (last, true));
+
+        let valid = NepaliDate::new(2077, 5, 19).unwrap();
+        assert_eq!(clamp_to_supported(2077, 5, 19), (valid, false));
+        assert_eq!(clamp_to_supported(last.year, 12, last.day), (last, false));
+
+        let (date, adjusted) = clamp_to_supported(2081, 0, 0);
+        assert_eq!((date.year, date.month, date.day), (2081, 1, 1));
+        assert!(adjusted);
+    }
+
     #[test]
     fn test_csv_data_loaded() {
         // Verify data is loaded (should have 126 years from 1975-2100)
@@ -104,12 +201,17 @@ mod tests {
     fn test_out_of_range_year_before() {
         let result = get_days_in_month(1974, 1);
         assert!(result.is_err(), "Year 1974 should be out of range");
+        assert!(matches!(result, Err(NpdatetimeError::BeforeEpoch(_))));
     }
 
     #[test]
     fn test_out_of_range_year_after() {
         let result = get_days_in_month(2101, 1);
         assert!(result.is_err(), "Year 2101 should be out of range");
+        assert!(matches!(
+            result,
+            Err(NpdatetimeError::AfterSupportedRange(_))
+        ));
     }
 
     #[test]
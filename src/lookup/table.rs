@@ -0,0 +1,118 @@
+//! Month-length tables anchored to a Gregorian epoch
+//!
+//! A [`CalendarTable`] carries its own first BS year and the Gregorian date of
+//! that year's Baisakh 1, so a [`super::NepaliCalendar`] built from a
+//! user-supplied table can reach years before the embedded 1975 BS start.
+
+use super::BS_MONTH_DATA;
+use crate::core::date::{BS_EPOCH_AD, BS_EPOCH_YEAR, validate_gregorian};
+use crate::core::error::{NpdatetimeError, Result};
+
+/// Days per month for a contiguous run of BS years, starting at `base_year`
+///
+/// # Examples:
+/// ```
+/// use npdatetime::lookup::CalendarTable;
+///
+/// let table = CalendarTable::builtin();
+/// assert_eq!(table.base_year(), 1975);
+/// assert_eq!(table.epoch_ad(), (1918, 4, 13));
+/// assert_eq!(table.last_year(), 2100);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CalendarTable {
+    base_year: i32,
+    /// Gregorian date of Baisakh 1 of `base_year`
+    epoch_ad: (i32, u8, u8),
+    month_data: Vec<[u8; 12]>,
+}
+
+impl CalendarTable {
+    /// Creates a table whose first row is `base_year`, starting on the Gregorian date `epoch_ad`
+    ///
+    /// Every month must have 29-32 days and at least one year must be given.
+    pub fn new(base_year: i32, epoch_ad: (i32, u8, u8), month_data: Vec<[u8; 12]>) -> Result<Self> {
+        validate_gregorian(epoch_ad.0, epoch_ad.1, epoch_ad.2)?;
+        if month_data.is_empty() {
+            return Err(NpdatetimeError::InvalidDate(
+                "Calendar table must contain at least one year".to_string(),
+            ));
+        }
+        for (offset, months) in month_data.iter().enumerate() {
+            if let Some(m) = months.iter().position(|d| !(29..=32).contains(d)) {
+                return Err(NpdatetimeError::InvalidDate(format!(
+                    "Year {} month {} has invalid day count: {}",
+                    base_year + offset as i32,
+                    m + 1,
+                    months[m]
+                )));
+            }
+        }
+
+        Ok(CalendarTable {
+            base_year,
+            epoch_ad,
+            month_data,
+        })
+    }
+
+    /// Returns the embedded table (1975-2100 BS, starting 1918-04-13)
+    pub fn builtin() -> Self {
+        CalendarTable {
+            base_year: BS_EPOCH_YEAR,
+            epoch_ad: BS_EPOCH_AD,
+            month_data: BS_MONTH_DATA.clone(),
+        }
+    }
+
+    /// Returns the first BS year covered by the table
+    pub fn base_year(&self) -> i32 {
+        self.base_year
+    }
+
+    /// Returns the last BS year covered by the table
+    pub fn last_year(&self) -> i32 {
+        self.base_year + self.month_data.len() as i32 - 1
+    }
+
+    /// Returns the Gregorian date of Baisakh 1 of [`CalendarTable::base_year`]
+    pub fn epoch_ad(&self) -> (i32, u8, u8) {
+        self.epoch_ad
+    }
+
+    /// Returns the month lengths, one row per year from [`CalendarTable::base_year`]
+    pub fn months(&self) -> &[[u8; 12]] {
+        &self.month_data
+    }
+}
+
+impl Default for CalendarTable {
+    fn default() -> Self {
+        Self::builtin()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_builtin_matches_embedded_data() {
+        let table = CalendarTable::builtin();
+        assert_eq!(table.months().len(), 126);
+        assert_eq!(table.months()[0], BS_MONTH_DATA[0]);
+    }
+
+    #[test]
+    fn test_rejects_invalid_tables() {
+        assert!(CalendarTable::new(1970, (1913, 4, 13), Vec::new()).is_err());
+        assert!(CalendarTable::new(1970, (1913, 2, 30), vec![[30; 12]]).is_err());
+
+        let mut months = [30u8; 12];
+        months[3] = 33;
+        assert!(matches!(
+            CalendarTable::new(1970, (1913, 4, 13), vec![months]),
+            Err(NpdatetimeError::InvalidDate(_))
+        ));
+    }
+}
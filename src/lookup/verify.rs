@@ -0,0 +1,80 @@
+//! Auditing the embedded table against astronomical month lengths
+
+use super::get_days_in_month;
+use crate::astronomical::AstronomicalCalendar;
+use crate::core::error::{NpdatetimeError, Result};
+use std::ops::RangeInclusive;
+
+/// A month whose length in the lookup table differs from the computed one
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Discrepancy {
+    pub year: i32,
+    /// Month (1-12)
+    pub month: u8,
+    /// Days according to the embedded lookup table
+    pub lookup: u8,
+    /// Days according to the Sankranti-based calculation
+    pub astro: u8,
+}
+
+/// Compares every month of `years` in the lookup table with the solar
+/// (Sankranti-based) month lengths
+///
+/// Returns the months that disagree, in calendar order. Years outside the
+/// embedded table yield the lookup error.
+///
+/// # Examples:
+/// ```
+/// use npdatetime::lookup::verify_against_astronomical;
+///
+/// for d in verify_against_astronomical(2081..=2081).unwrap() {
+///     println!("{}-{:02}: lookup {} vs astro {}", d.year, d.month, d.lookup, d.astro);
+/// }
+/// ```
+pub fn verify_against_astronomical(years: RangeInclusive<i32>) -> Result<Vec<Discrepancy>> {
+    let calendar = AstronomicalCalendar::new();
+    let mut discrepancies = Vec::new();
+
+    for year in years {
+        let info = calendar
+            .get_year_info(year)
+            .map_err(NpdatetimeError::CalculationError)?;
+
+        for (month, &astro) in (1..=12).zip(&info.month_lengths) {
+            let lookup = get_days_in_month(year, month)?;
+            if lookup != astro {
+                discrepancies.push(Discrepancy {
+                    year,
+                    month,
+                    lookup,
+                    astro,
+                });
+            }
+        }
+    }
+
+    Ok(discrepancies)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_verify_small_range() {
+        let discrepancies = verify_against_astronomical(2080..=2081).unwrap();
+        for d in &discrepancies {
+            assert!((2080..=2081).contains(&d.year));
+            assert!((1..=12).contains(&d.month));
+            assert_ne!(d.lookup, d.astro);
+            assert_eq!(get_days_in_month(d.year, d.month).unwrap(), d.lookup);
+        }
+        assert!(
+            discrepancies
+                .windows(2)
+                .all(|w| (w[0].year, w[0].month) < (w[1].year, w[1].month))
+        );
+
+        assert!(verify_against_astronomical(2101..=2101).is_err());
+    }
+}